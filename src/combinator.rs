@@ -1,2 +1,23 @@
+pub mod chain;
+pub mod enumerate;
 pub mod filter;
+pub mod filter_map;
+pub mod filter_ref;
+pub mod flat_map;
+pub mod flat_map_iter;
+pub mod flatten;
+pub mod flatten_iter;
+pub mod fuse;
+pub mod inspect;
 pub mod map;
+pub mod map_buffered;
+pub mod map_mut;
+pub mod map_unordered;
+pub mod map_while;
+pub mod scan;
+pub mod skip;
+pub mod skip_while;
+pub mod step_by;
+pub mod take;
+pub mod take_while;
+pub mod zip;
@@ -0,0 +1,75 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that yields every item of the first iterator, followed by
+    /// every item of the second one.
+    ///
+    /// The first iterator is dropped as soon as it is exhausted, and is never polled again.
+    ///
+    /// This struct is created by the `.async_chain()` method on `AsyncIterTools`.
+    #[derive(Clone, Debug)]
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncChain<A, B> {
+        #[pin]
+        first: Option<A>,
+        #[pin]
+        second: B,
+    }
+}
+
+impl<A, B> AsyncChain<A, B> {
+    /// Creates a new `AsyncChain` yielding the items of `first` and then those of `second`.
+    pub(crate) fn new(first: A, second: B) -> Self {
+        Self {
+            first: Some(first),
+            second,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncChain`.
+///
+/// The `poll_next()` method yields the items of the first iterator until it is exhausted, and
+/// then yields the items of the second one.
+impl<A, B> AsyncIterator for AsyncChain<A, B>
+where
+    A: AsyncIterator,
+    B: AsyncIterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if let Some(first) = this.first.as_mut().as_pin_mut() {
+            match ready!(first.poll_next(cx)) {
+                Some(item) => return Poll::Ready(Some(item)),
+                None => this.first.set(None),
+            }
+        }
+
+        this.second.poll_next(cx)
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let (second_lower, second_upper) = self.second.async_size_hint();
+        let Some(first) = self.first.as_ref() else {
+            return (second_lower, second_upper);
+        };
+
+        let (first_lower, first_upper) = first.async_size_hint();
+        (
+            first_lower.saturating_add(second_lower),
+            first_upper
+                .zip(second_upper)
+                .and_then(|(first, second)| first.checked_add(second)),
+        )
+    }
+}
@@ -0,0 +1,55 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that yields each item of the underlying iterator together
+    /// with its index, starting from zero.
+    ///
+    /// This struct is created by the `.async_enumerate()` method on `AsyncIterTools`.
+    #[derive(Clone, Debug)]
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncEnumerate<I> {
+        #[pin]
+        iter: I,
+        count: usize,
+    }
+}
+
+impl<I> AsyncEnumerate<I> {
+    /// Creates a new `AsyncEnumerate` attaching indexes to the items of `iter`.
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, count: 0 }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncEnumerate`.
+///
+/// The `poll_next()` method yields the next item of the underlying iterator as an
+/// `(index, item)` pair.
+impl<I> AsyncIterator for AsyncEnumerate<I>
+where
+    I: AsyncIterator,
+{
+    type Item = (usize, I::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        let next = ready!(this.iter.poll_next(cx));
+        Poll::Ready(next.map(|item| {
+            let index = *this.count;
+            *this.count += 1;
+            (index, item)
+        }))
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.async_size_hint()
+    }
+}
@@ -1,23 +1,47 @@
 use std::{
     fmt::{Debug, Formatter},
     pin::Pin,
-    task::{Context, Poll},
+    task::{Context, Poll, ready},
     vec::IntoIter,
 };
 
+use pin_project_lite::pin_project;
+
 use crate::iter::{
     AsyncIterator,
     sync_iter::{PollSyncIter, SyncIter},
 };
 
-#[derive(Clone)]
-#[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
-pub struct AsyncFilter<I, F> {
-    pub(crate) iter: I,
-    pub(crate) f: F,
+pin_project! {
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncFilter<I, F, Fut>
+    where
+        I: AsyncIterator,
+    {
+        #[pin]
+        pub(crate) iter: I,
+        pub(crate) f: F,
+        pub(crate) pending: Option<(I::Item, Pin<Box<Fut>>)>,
+        pub(crate) collected: Vec<I::Item>,
+    }
+}
+
+impl<I, F, Fut> AsyncFilter<I, F, Fut>
+where
+    I: AsyncIterator,
+{
+    /// Creates a new `AsyncFilter` keeping the items of `iter` for which `f` resolves to `true`.
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            pending: None,
+            collected: Vec::new(),
+        }
+    }
 }
 
-impl<I, F, Fut> AsyncIterator for AsyncFilter<I, F>
+impl<I, F, Fut> AsyncIterator for AsyncFilter<I, F, Fut>
 where
     I: AsyncIterator,
     F: FnMut(I::Item) -> Fut,
@@ -26,34 +50,54 @@ where
 {
     type Item = I::Item;
 
-    async fn next_async(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.iter.next_async().await {
-            (self.f)(next.clone()).await.then_some(next)
-        } else {
-            None
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some((_, predicate)) = this.pending.as_mut() {
+                let keep = ready!(predicate.as_mut().poll(cx));
+                let pending = this.pending.take();
+                if keep {
+                    return Poll::Ready(pending.map(|(next, _)| next));
+                }
+                continue;
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => {
+                    let predicate = Box::pin((this.f)(next.clone()));
+                    *this.pending = Some((next, predicate));
+                }
+                None => return Poll::Ready(None),
+            }
         }
     }
 
     fn async_size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.async_size_hint()
+        let pending = usize::from(self.pending.is_some());
+        let (_, upper) = self.iter.async_size_hint();
+        (0, upper.and_then(|upper| upper.checked_add(pending)))
     }
 }
 
-impl<I, F, Fut> PollSyncIter for AsyncFilter<I, F>
+impl<I, F, Fut> PollSyncIter for AsyncFilter<I, F, Fut>
 where
     I: AsyncIterator,
     F: FnMut(I::Item) -> Fut,
     Fut: Future<Output = bool>,
     I::Item: Clone,
 {
+    fn collected_items(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.collected
+    }
 }
 
-impl<I, F, Fut> Future for AsyncFilter<I, F>
+impl<I, F, Fut> Future for AsyncFilter<I, F, Fut>
 where
     I: AsyncIterator + Unpin,
-    F: FnMut(I::Item) -> Fut + Unpin + Send,
-    Fut: Future<Output = bool> + Send,
-    I::Item: Clone,
+    F: FnMut(I::Item) -> Fut + Unpin,
+    Fut: Future<Output = bool>,
+    I::Item: Clone + Unpin,
 {
     type Output = SyncIter<IntoIter<I::Item>>;
 
@@ -62,7 +106,7 @@ where
     }
 }
 
-impl<I, F> Debug for AsyncFilter<I, F>
+impl<I, F, Fut> Debug for AsyncFilter<I, F, Fut>
 where
     I: AsyncIterator + Debug,
 {
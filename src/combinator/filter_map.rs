@@ -0,0 +1,95 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that maps each item with an async function and yields only
+    /// the `Some` values it resolves to.
+    ///
+    /// `AsyncFilterMap` is similar to the standard `.filter_map()` method on iterators, and
+    /// replaces a [`AsyncMap`](super::map::AsyncMap) returning `Option<B>` followed by filtering
+    /// out the `None`s.
+    ///
+    /// This struct is created by the `.filter_map_async()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous mapping function, which produces a future for each item.
+    /// - `Fut`: The future produced by the mapping function, resolving to an `Option`.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncFilterMap<I, F, Fut> {
+        #[pin]
+        iter: I,
+        f: F,
+        pending: Option<Pin<Box<Fut>>>,
+    }
+}
+
+impl<I, F, Fut> AsyncFilterMap<I, F, Fut> {
+    /// Creates a new `AsyncFilterMap` applying `f` to every item of `iter`.
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            pending: None,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncFilterMap`.
+///
+/// The `poll_next()` method maps items from the underlying iterator one at a time, skipping those
+/// that resolve to `None`, and yields the first `Some` value.
+impl<B, I, F, Fut> AsyncIterator for AsyncFilterMap<I, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Option<B>>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(pending) = this.pending.as_mut() {
+                let output = ready!(pending.as_mut().poll(cx));
+                *this.pending = None;
+                if output.is_some() {
+                    return Poll::Ready(output);
+                }
+                continue;
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => *this.pending = Some(Box::pin((this.f)(next))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+        let (_, upper) = self.iter.async_size_hint();
+        (0, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncFilterMap` that includes debug output for the
+/// underlying iterator. The mapping function is not shown.
+impl<I, F, Fut> Debug for AsyncFilterMap<I, F, Fut>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFilterMap")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that keeps the items for which an `AsyncFnMut` predicate,
+    /// given a reference to the item, resolves to `true`.
+    ///
+    /// `AsyncFilterRef` behaves like [`AsyncFilter`](super::filter::AsyncFilter), except that the
+    /// predicate borrows each item instead of receiving a clone of it, so the item type does not
+    /// need to implement `Clone`. The predicate and the item are moved into a boxed call future
+    /// while it runs, and handed back to the adapter once it completes. The future may therefore
+    /// borrow both the item and the predicate's captured state across its `.await` points.
+    ///
+    /// The call future keeps its concrete type, so the adapter is `Send` whenever the predicate,
+    /// the items and the futures the predicate returns are. As with
+    /// [`AsyncMapMut`](super::map_mut::AsyncMapMut), that type cannot be named.
+    ///
+    /// This struct is created by the `.filter_async_ref()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous predicate.
+    /// - `Fut`: The call future, which runs the predicate on one item and hands both back.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncFilterRef<I, F, Fut>
+    where
+        I: AsyncIterator,
+    {
+        #[pin]
+        iter: I,
+        f: Option<F>,
+        call: fn(F, I::Item) -> Fut,
+        pending: Option<Pin<Box<Fut>>>,
+    }
+}
+
+impl<I, F, Fut> AsyncFilterRef<I, F, Fut>
+where
+    I: AsyncIterator,
+{
+    /// Creates a new `AsyncFilterRef` keeping the items of `iter` for which `f` resolves to
+    /// `true`, running each call with `call`.
+    pub(crate) fn new(iter: I, f: F, call: fn(F, I::Item) -> Fut) -> Self {
+        Self {
+            iter,
+            f: Some(f),
+            call,
+            pending: None,
+        }
+    }
+}
+
+/// Calls `f` with a reference to `item`, handing the predicate and the item back together with
+/// the result so that the call future owns everything it borrows from.
+pub(crate) async fn call_mut<F, T>(mut f: F, item: T) -> (F, T, bool)
+where
+    F: AsyncFnMut(&T) -> bool,
+{
+    let keep = f(&item).await;
+    (f, item, keep)
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncFilterRef`.
+///
+/// The `poll_next()` method pulls items from the underlying iterator and runs the predicate on a
+/// reference to each of them, yielding the first item that is accepted.
+///
+/// A call that panics drops the predicate along with its future, so polling the adapter again
+/// after such a panic panics as well.
+impl<I, F, Fut> AsyncIterator for AsyncFilterRef<I, F, Fut>
+where
+    I: AsyncIterator,
+    Fut: Future<Output = (F, I::Item, bool)>,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // The call future is taken out while it is polled, so a panicking call drops it
+            // together with the predicate instead of leaving it behind to be resumed.
+            if let Some(mut pending) = this.pending.take() {
+                let Poll::Ready((f, next, keep)) = pending.as_mut().poll(cx) else {
+                    *this.pending = Some(pending);
+                    return Poll::Pending;
+                };
+                *this.f = Some(f);
+                if keep {
+                    return Poll::Ready(Some(next));
+                }
+                continue;
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => {
+                    let f = this.f.take().expect("polled after panic");
+                    *this.pending = Some(Box::pin((this.call)(f, next)));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+        let (_, upper) = self.iter.async_size_hint();
+        (0, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncFilterRef` that includes debug output for the
+/// underlying iterator. The predicate is not shown.
+impl<I, F, Fut> Debug for AsyncFilterRef<I, F, Fut>
+where
+    I: AsyncIterator + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFilterRef")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that maps each item to an async iterator with an async
+    /// function, and yields the items of each of those in turn.
+    ///
+    /// `AsyncFlatMap` is similar to the standard `.flat_map()` method on iterators. The mapping
+    /// function returns a future, so fetching the inner items, such as a page of records, can
+    /// itself be asynchronous. The next item of the underlying iterator is only pulled once the
+    /// current inner iterator is exhausted.
+    ///
+    /// This struct is created by the `.flat_map_async()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous mapping function, which produces a future for each item.
+    /// - `Fut`: The future produced by the mapping function, resolving to an inner async iterator.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncFlatMap<I, F, Fut>
+    where
+        Fut: Future,
+    {
+        #[pin]
+        iter: I,
+        f: F,
+        pending: Option<Pin<Box<Fut>>>,
+        #[pin]
+        inner: Option<Fut::Output>,
+    }
+}
+
+impl<I, F, Fut> AsyncFlatMap<I, F, Fut>
+where
+    Fut: Future,
+{
+    /// Creates a new `AsyncFlatMap` applying `f` to every item of `iter`.
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            pending: None,
+            inner: None,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncFlatMap`.
+///
+/// The `poll_next()` method yields the items of the current inner iterator, and maps the next
+/// item of the underlying iterator to a new inner iterator once it is exhausted.
+impl<U, I, F, Fut> AsyncIterator for AsyncFlatMap<I, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = U>,
+    U: AsyncIterator,
+{
+    type Item = U::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(inner) = this.inner.as_mut().as_pin_mut() {
+                match ready!(inner.poll_next(cx)) {
+                    Some(item) => return Poll::Ready(Some(item)),
+                    None => this.inner.set(None),
+                }
+            }
+
+            if let Some(pending) = this.pending.as_mut() {
+                let inner = ready!(pending.as_mut().poll(cx));
+                *this.pending = None;
+                this.inner.set(Some(inner));
+                continue;
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => *this.pending = Some(Box::pin((this.f)(next))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self
+            .inner
+            .as_ref()
+            .map_or((0, Some(0)), AsyncIterator::async_size_hint);
+
+        match (self.pending.is_some(), self.iter.async_size_hint()) {
+            (false, (_, Some(0))) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncFlatMap` that includes debug output for the
+/// underlying iterator and the current inner iterator. The mapping function is not shown.
+impl<I, F, Fut> Debug for AsyncFlatMap<I, F, Fut>
+where
+    I: Debug,
+    Fut: Future,
+    Fut::Output: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFlatMap")
+            .field("iter", &self.iter)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
@@ -0,0 +1,122 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that maps each item to a synchronous iterator with an
+    /// async function, and yields the items of each of those in turn.
+    ///
+    /// `AsyncFlatMapIter` works like [`AsyncFlatMap`](super::flat_map::AsyncFlatMap), but the
+    /// future resolves to any `IntoIterator`, such as a `Vec`, whose iterator is driven
+    /// synchronously. The next item of the underlying iterator is only pulled once the current
+    /// inner iterator is exhausted.
+    ///
+    /// This struct is created by the `.flat_map_iter_async()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous mapping function, which produces a future for each item.
+    /// - `Fut`: The future produced by the mapping function, resolving to an `IntoIterator`.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncFlatMapIter<I, F, Fut>
+    where
+        Fut: Future,
+        Fut::Output: IntoIterator,
+    {
+        #[pin]
+        iter: I,
+        f: F,
+        pending: Option<Pin<Box<Fut>>>,
+        inner: Option<<Fut::Output as IntoIterator>::IntoIter>,
+    }
+}
+
+impl<I, F, Fut> AsyncFlatMapIter<I, F, Fut>
+where
+    Fut: Future,
+    Fut::Output: IntoIterator,
+{
+    /// Creates a new `AsyncFlatMapIter` applying `f` to every item of `iter`.
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            pending: None,
+            inner: None,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncFlatMapIter`.
+///
+/// The `poll_next()` method yields the items of the current inner iterator without suspending,
+/// and maps the next item of the underlying iterator to a new inner iterator once it is exhausted.
+impl<U, I, F, Fut> AsyncIterator for AsyncFlatMapIter<I, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = U>,
+    U: IntoIterator,
+{
+    type Item = U::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(inner) = this.inner.as_mut() {
+                match inner.next() {
+                    Some(item) => return Poll::Ready(Some(item)),
+                    None => *this.inner = None,
+                }
+            }
+
+            if let Some(pending) = this.pending.as_mut() {
+                let inner = ready!(pending.as_mut().poll(cx));
+                *this.pending = None;
+                *this.inner = Some(inner.into_iter());
+                continue;
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => *this.pending = Some(Box::pin((this.f)(next))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self
+            .inner
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint);
+
+        match (self.pending.is_some(), self.iter.async_size_hint()) {
+            (false, (_, Some(0))) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncFlatMapIter` that includes debug output for the
+/// underlying iterator and the current inner iterator. The mapping function is not shown.
+impl<I, F, Fut> Debug for AsyncFlatMapIter<I, F, Fut>
+where
+    I: Debug,
+    Fut: Future,
+    Fut::Output: IntoIterator,
+    <Fut::Output as IntoIterator>::IntoIter: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFlatMapIter")
+            .field("iter", &self.iter)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
@@ -0,0 +1,96 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that yields the items of every async iterator yielded by
+    /// the underlying iterator in turn.
+    ///
+    /// `AsyncFlatten` is similar to the standard `.flatten()` method on iterators. The next inner
+    /// iterator is only pulled from the underlying iterator once the current one is exhausted.
+    ///
+    /// This struct is created by the `.async_flatten()` method on `AsyncIterTools`.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncFlatten<I>
+    where
+        I: AsyncIterator,
+    {
+        #[pin]
+        iter: I,
+        #[pin]
+        inner: Option<I::Item>,
+    }
+}
+
+impl<I> AsyncFlatten<I>
+where
+    I: AsyncIterator,
+{
+    /// Creates a new `AsyncFlatten` over the inner iterators yielded by `iter`.
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, inner: None }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncFlatten`.
+///
+/// The `poll_next()` method yields the items of the current inner iterator, and pulls the next
+/// inner iterator from the underlying iterator once it is exhausted.
+impl<I> AsyncIterator for AsyncFlatten<I>
+where
+    I: AsyncIterator,
+    I::Item: AsyncIterator,
+{
+    type Item = <I::Item as AsyncIterator>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(inner) = this.inner.as_mut().as_pin_mut() {
+                match ready!(inner.poll_next(cx)) {
+                    Some(item) => return Poll::Ready(Some(item)),
+                    None => this.inner.set(None),
+                }
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(inner) => this.inner.set(Some(inner)),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self
+            .inner
+            .as_ref()
+            .map_or((0, Some(0)), AsyncIterator::async_size_hint);
+
+        match self.iter.async_size_hint() {
+            (_, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncFlatten` that includes debug output for the
+/// underlying iterator and the current inner iterator.
+impl<I> Debug for AsyncFlatten<I>
+where
+    I: AsyncIterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFlatten")
+            .field("iter", &self.iter)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
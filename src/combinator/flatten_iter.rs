@@ -0,0 +1,100 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that yields the items of every `IntoIterator` yielded by
+    /// the underlying iterator in turn.
+    ///
+    /// `AsyncFlattenIter` works like [`AsyncFlatten`](super::flatten::AsyncFlatten), but the inner
+    /// items are synchronous collections or iterators, which are driven without suspending. The
+    /// next inner iterator is only pulled from the underlying iterator once the current one is
+    /// exhausted.
+    ///
+    /// This struct is created by the `.async_flatten_iter()` method on `AsyncIterTools`.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncFlattenIter<I>
+    where
+        I: AsyncIterator,
+        I::Item: IntoIterator,
+    {
+        #[pin]
+        iter: I,
+        inner: Option<<I::Item as IntoIterator>::IntoIter>,
+    }
+}
+
+impl<I> AsyncFlattenIter<I>
+where
+    I: AsyncIterator,
+    I::Item: IntoIterator,
+{
+    /// Creates a new `AsyncFlattenIter` over the inner iterators yielded by `iter`.
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, inner: None }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncFlattenIter`.
+///
+/// The `poll_next()` method yields the items of the current inner iterator without suspending,
+/// and pulls the next inner iterator from the underlying iterator once it is exhausted.
+impl<I> AsyncIterator for AsyncFlattenIter<I>
+where
+    I: AsyncIterator,
+    I::Item: IntoIterator,
+{
+    type Item = <I::Item as IntoIterator>::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(inner) = this.inner.as_mut() {
+                match inner.next() {
+                    Some(item) => return Poll::Ready(Some(item)),
+                    None => *this.inner = None,
+                }
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(inner) => *this.inner = Some(inner.into_iter()),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self
+            .inner
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint);
+
+        match self.iter.async_size_hint() {
+            (_, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncFlattenIter` that includes debug output for the
+/// underlying iterator and the current inner iterator.
+impl<I> Debug for AsyncFlattenIter<I>
+where
+    I: AsyncIterator + Debug,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::IntoIter: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncFlattenIter")
+            .field("iter", &self.iter)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
@@ -0,0 +1,60 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that returns `None` forever once the underlying iterator
+    /// has returned `None` for the first time.
+    ///
+    /// The underlying iterator is dropped when it is exhausted, so it is never polled past its end.
+    ///
+    /// This struct is created by the `.async_fuse()` method on `AsyncIterTools`.
+    #[derive(Clone, Debug)]
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncFuse<I> {
+        #[pin]
+        iter: Option<I>,
+    }
+}
+
+impl<I> AsyncFuse<I> {
+    /// Creates a new `AsyncFuse` over `iter`.
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter: Some(iter) }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncFuse`.
+///
+/// The `poll_next()` method yields the items of the underlying iterator, and keeps returning
+/// `None` after it first did so.
+impl<I> AsyncIterator for AsyncFuse<I>
+where
+    I: AsyncIterator,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let Some(iter) = this.iter.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+
+        let next = ready!(iter.poll_next(cx));
+        if next.is_none() {
+            this.iter.set(None);
+        }
+        Poll::Ready(next)
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        self.iter
+            .as_ref()
+            .map_or((0, Some(0)), AsyncIterator::async_size_hint)
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that runs an async side effect, given a reference to each
+    /// item, before yielding the item unchanged.
+    ///
+    /// Each item is held by the adapter until its side effect has completed, so side effects run
+    /// one at a time and in order.
+    ///
+    /// This struct is created by the `.inspect_async()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous side effect, which produces a future for each borrowed item.
+    /// - `Fut`: The future produced by the side effect.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncInspect<I, F, Fut>
+    where
+        I: AsyncIterator,
+    {
+        #[pin]
+        iter: I,
+        f: F,
+        pending: Option<(I::Item, Pin<Box<Fut>>)>,
+    }
+}
+
+impl<I, F, Fut> AsyncInspect<I, F, Fut>
+where
+    I: AsyncIterator,
+{
+    /// Creates a new `AsyncInspect` running `f` for every item of `iter`.
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            pending: None,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncInspect`.
+///
+/// The `poll_next()` method pulls the next item from the underlying iterator, awaits the side
+/// effect for it, and then yields the item.
+impl<I, F, Fut> AsyncIterator for AsyncInspect<I, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(&I::Item) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if this.pending.is_none() {
+            match ready!(this.iter.poll_next(cx)) {
+                Some(next) => {
+                    let effect = Box::pin((this.f)(&next));
+                    *this.pending = Some((next, effect));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+
+        if let Some((_, effect)) = this.pending.as_mut() {
+            ready!(effect.as_mut().poll(cx));
+        }
+        Poll::Ready(this.pending.take().map(|(next, _)| next))
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+        let (lower, upper) = self.iter.async_size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncInspect` that includes debug output for the
+/// underlying iterator. The side effect is not shown.
+impl<I, F, Fut> Debug for AsyncInspect<I, F, Fut>
+where
+    I: AsyncIterator + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncInspect")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
@@ -1,51 +1,82 @@
 use std::{
     fmt::{Debug, Formatter},
     pin::Pin,
-    task::{Context, Poll},
+    task::{Context, Poll, ready},
     vec::IntoIter,
 };
 
+use pin_project_lite::pin_project;
+
 use crate::iter::{
     AsyncIterator,
     sync_iter::{PollSyncIter, SyncIter},
 };
 
-/// An asynchronous iterator adapter that maps each item to a new value using an async function.
-///
-/// `AsyncMap` is similar to the standard `.map()` method on iterators, but it supports asynchronous closures
-/// by allowing the mapping function to return a `Future`. This makes it useful for scenarios where each item
-/// in an iterator needs to be processed using asynchronous logic.
-///
-/// This struct is created by the `.map_async()` method on `AsyncIterTools`.
-///
-/// # Type Parameters
-/// - `I`: The underlying async iterator.
-/// - `F`: The asynchronous mapping function, which produces a future for each item.
-#[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
-pub struct AsyncMap<I, F> {
-    pub(crate) iter: I,
-    pub(crate) f: F,
+pin_project! {
+    /// An asynchronous iterator adapter that maps each item to a new value using an async function.
+    ///
+    /// `AsyncMap` is similar to the standard `.map()` method on iterators, but it supports
+    /// asynchronous closures by allowing the mapping function to return a `Future`. This makes it
+    /// useful for scenarios where each item in an iterator needs to be processed using asynchronous
+    /// logic.
+    ///
+    /// This struct is created by the `.map_async()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous mapping function, which produces a future for each item.
+    /// - `Fut`: The future produced by the mapping function.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncMap<I, F, Fut>
+    where
+        Fut: Future,
+    {
+        #[pin]
+        pub(crate) iter: I,
+        pub(crate) f: F,
+        pub(crate) pending: Option<Pin<Box<Fut>>>,
+        pub(crate) collected: Vec<Fut::Output>,
+    }
+}
+
+impl<I, F, Fut> AsyncMap<I, F, Fut>
+where
+    Fut: Future,
+{
+    /// Creates a new `AsyncMap` applying `f` to every item of `iter`.
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            pending: None,
+            collected: Vec::new(),
+        }
+    }
 }
 
 /// Enables conversion of an `AsyncMap` into a synchronous `SyncIter` by polling the async iterator
 /// and collecting items into a vector. Implements the `PollSyncIter` trait, allowing integration with
 /// the `Future` implementation below.
-impl<B, I, F, Fut> PollSyncIter for AsyncMap<I, F>
+impl<B, I, F, Fut> PollSyncIter for AsyncMap<I, F, Fut>
 where
-    I: AsyncIterator + Unpin,
-    F: FnMut(I::Item) -> Fut + Unpin + Send,
-    Fut: Future<Output = B> + Send,
+    I: AsyncIterator,
+    F: FnMut(I::Item) -> Fut + Unpin,
+    Fut: Future<Output = B>,
 {
+    fn collected_items(&mut self) -> &mut Vec<Self::Item> {
+        &mut self.collected
+    }
 }
 
 /// Allows an `AsyncMap` to be `.await`ed directly, returning a synchronous iterator (`SyncIter`) over
-/// the collected results of the async mapping operation. This makes it possible to use `.await` on
-/// `AsyncMap` to collect all results at once in blocking contexts like `block_on`.
-impl<B, I, F, Fut> Future for AsyncMap<I, F>
+/// the collected results of the async mapping operation. Items collected so far are kept in the
+/// `AsyncMap` between polls, so a pending mapped future suspends the task until it is woken.
+impl<B, I, F, Fut> Future for AsyncMap<I, F, Fut>
 where
     I: AsyncIterator + Unpin,
-    F: FnMut(I::Item) -> Fut + Unpin + Send,
-    Fut: Future<Output = B> + Send,
+    F: FnMut(I::Item) -> Fut + Unpin,
+    Fut: Future<Output = B>,
+    B: Unpin,
 {
     type Output = SyncIter<IntoIter<B>>;
 
@@ -57,34 +88,50 @@ where
 /// Implements the `AsyncIterator` trait for `AsyncMap`, yielding the result of applying
 /// the async mapping function `f` to each item from the underlying iterator `iter`.
 ///
-/// The `next_async()` method awaits the next item and then applies the mapping function,
-/// awaiting its result before yielding it downstream.
-impl<B, I, F, Fut> AsyncIterator for AsyncMap<I, F>
+/// The `poll_next()` method pulls the next item and then applies the mapping function,
+/// polling its result before yielding it downstream. The mapped future is stored in the
+/// `AsyncMap`, so dropping an unfinished `next_async()` future does not lose the item.
+impl<B, I, F, Fut> AsyncIterator for AsyncMap<I, F, Fut>
 where
     I: AsyncIterator,
-    F: FnMut(I::Item) -> Fut + Send,
-    Fut: Future<Output = B> + Send,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = B>,
 {
     type Item = B;
 
-    async fn next_async(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.iter.next_async().await {
-            Some((self.f)(next).await)
-        } else {
-            None
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(pending) = this.pending.as_mut() {
+                let output = ready!(pending.as_mut().poll(cx));
+                *this.pending = None;
+                return Poll::Ready(Some(output));
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => *this.pending = Some(Box::pin((this.f)(next))),
+                None => return Poll::Ready(None),
+            }
         }
     }
 
     fn async_size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.async_size_hint()
+        let pending = usize::from(self.pending.is_some());
+        let (lower, upper) = self.iter.async_size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
     }
 }
 
 /// Provides a `Debug` implementation for `AsyncMap` that includes debug output for the underlying iterator.
 /// The mapping function `f` is not shown due to lack of generic support for `Debug` on closures.
-impl<I, F> Debug for AsyncMap<I, F>
+impl<I, F, Fut> Debug for AsyncMap<I, F, Fut>
 where
     I: AsyncIterator + Debug,
+    Fut: Future,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AsyncMap")
@@ -0,0 +1,168 @@
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that maps each item with an async function, keeping up to
+    /// `limit` of the resulting futures in flight at once while yielding their outputs in input
+    /// order.
+    ///
+    /// `AsyncMapBuffered` behaves like [`AsyncMap`](super::map::AsyncMap), except that it does not
+    /// wait for one mapped future to finish before pulling the next item from the underlying
+    /// iterator. A slow future only holds back the outputs that come after it, never the work
+    /// itself.
+    ///
+    /// Each mapped future is boxed, and every wake-up polls all the futures in flight rather than
+    /// only the one that was woken. A poll therefore costs O(`limit`), however many items the
+    /// underlying iterator yields.
+    ///
+    /// This struct is created by the `.map_async_buffered()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous mapping function, which produces a future for each item.
+    /// - `Fut`: The future produced by the mapping function.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncMapBuffered<I, F, Fut>
+    where
+        Fut: Future,
+    {
+        #[pin]
+        iter: I,
+        f: F,
+        limit: usize,
+        in_flight: VecDeque<Slot<Fut>>,
+        exhausted: bool,
+    }
+}
+
+/// A mapped future that is either still running or has finished and is waiting for its turn.
+enum Slot<Fut>
+where
+    Fut: Future,
+{
+    Pending(Pin<Box<Fut>>),
+    Done(Fut::Output),
+}
+
+impl<I, F, Fut> AsyncMapBuffered<I, F, Fut>
+where
+    Fut: Future,
+{
+    /// Creates a new `AsyncMapBuffered` running at most `limit` mapped futures concurrently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    pub(crate) fn new(iter: I, limit: usize, f: F) -> Self {
+        assert!(limit > 0, "concurrency limit must be greater than zero");
+
+        Self {
+            iter,
+            f,
+            limit,
+            in_flight: VecDeque::with_capacity(limit),
+            exhausted: false,
+        }
+    }
+}
+
+/// Polls every running future in `in_flight`, storing the outputs of those that finish.
+///
+/// The futures share the task's waker, so there is no way to tell which of them was woken, and
+/// all of them are polled again.
+///
+/// Returns `Poll::Ready` once the oldest future has an output available.
+fn poll_in_flight<Fut>(in_flight: &mut VecDeque<Slot<Fut>>, cx: &mut Context<'_>) -> Poll<()>
+where
+    Fut: Future,
+{
+    for slot in in_flight.iter_mut() {
+        if let Slot::Pending(fut) = slot
+            && let Poll::Ready(output) = fut.as_mut().poll(cx)
+        {
+            *slot = Slot::Done(output);
+        }
+    }
+
+    match in_flight.front() {
+        Some(Slot::Done(_)) => Poll::Ready(()),
+        _ => Poll::Pending,
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncMapBuffered`.
+///
+/// The `poll_next()` method keeps pulling items from the underlying iterator until `limit`
+/// futures are in flight, drives all of them concurrently, and yields the output of the oldest one
+/// as soon as it is done.
+impl<B, I, F, Fut> AsyncIterator for AsyncMapBuffered<I, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = B>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while !*this.exhausted && this.in_flight.len() < *this.limit {
+            match this.iter.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => this
+                    .in_flight
+                    .push_back(Slot::Pending(Box::pin((this.f)(item)))),
+                Poll::Ready(None) => *this.exhausted = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if poll_in_flight(this.in_flight, cx).is_ready()
+            && let Some(Slot::Done(output)) = this.in_flight.pop_front()
+        {
+            return Poll::Ready(Some(output));
+        }
+
+        if *this.exhausted && this.in_flight.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let in_flight = self.in_flight.len();
+        if self.exhausted {
+            return (in_flight, Some(in_flight));
+        }
+
+        let (lower, upper) = self.iter.async_size_hint();
+        (
+            lower.saturating_add(in_flight),
+            upper.and_then(|upper| upper.checked_add(in_flight)),
+        )
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncMapBuffered` that includes debug output for the
+/// underlying iterator. The mapping function and the futures in flight are not shown.
+impl<I, F, Fut> Debug for AsyncMapBuffered<I, F, Fut>
+where
+    I: AsyncIterator + Debug,
+    Fut: Future,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncMapBuffered")
+            .field("iter", &self.iter)
+            .field("limit", &self.limit)
+            .field("in_flight", &self.in_flight.len())
+            .finish()
+    }
+}
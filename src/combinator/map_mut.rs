@@ -0,0 +1,129 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that maps each item to a new value using an `AsyncFnMut`
+    /// closure.
+    ///
+    /// `AsyncMapMut` behaves like [`AsyncMap`](super::map::AsyncMap), except that the future
+    /// returned by the closure may borrow the closure's captured state, so a native async closure
+    /// such as `async |item| { seen.push(item); item * 2 }` can be used. The closure is moved into
+    /// the boxed call future while it runs, and handed back to the adapter once it completes.
+    ///
+    /// The call future keeps its concrete type, so the adapter is `Send` whenever the closure and
+    /// the futures it returns are. That type cannot be named, so the adapter returned by
+    /// `.map_async_mut()` can only be stored behind a generic parameter or `impl AsyncIterator`.
+    ///
+    /// This struct is created by the `.map_async_mut()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous mapping closure.
+    /// - `Fut`: The call future, which runs the closure on one item and hands it back.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncMapMut<I, F, Fut>
+    where
+        I: AsyncIterator,
+    {
+        #[pin]
+        iter: I,
+        f: Option<F>,
+        call: fn(F, I::Item) -> Fut,
+        pending: Option<Pin<Box<Fut>>>,
+    }
+}
+
+impl<I, F, Fut> AsyncMapMut<I, F, Fut>
+where
+    I: AsyncIterator,
+{
+    /// Creates a new `AsyncMapMut` applying `f` to every item of `iter`, running each call with
+    /// `call`.
+    pub(crate) fn new(iter: I, f: F, call: fn(F, I::Item) -> Fut) -> Self {
+        Self {
+            iter,
+            f: Some(f),
+            call,
+            pending: None,
+        }
+    }
+}
+
+/// Calls `f` with `item`, handing the closure back together with its output so that the call
+/// future owns everything it borrows from.
+pub(crate) async fn call_mut<F, T, B>(mut f: F, item: T) -> (F, B)
+where
+    F: AsyncFnMut(T) -> B,
+{
+    let output = f(item).await;
+    (f, output)
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncMapMut`.
+///
+/// The `poll_next()` method pulls the next item, moves the closure into a call future for it, and
+/// yields the output once that future completes.
+///
+/// A call that panics drops the closure along with its future, so polling the adapter again after
+/// such a panic panics as well.
+impl<B, I, F, Fut> AsyncIterator for AsyncMapMut<I, F, Fut>
+where
+    I: AsyncIterator,
+    Fut: Future<Output = (F, B)>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            // The call future is taken out while it is polled, so a panicking call drops it
+            // together with the closure instead of leaving it behind to be resumed.
+            if let Some(mut pending) = this.pending.take() {
+                let Poll::Ready((f, output)) = pending.as_mut().poll(cx) else {
+                    *this.pending = Some(pending);
+                    return Poll::Pending;
+                };
+                *this.f = Some(f);
+                return Poll::Ready(Some(output));
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => {
+                    let f = this.f.take().expect("polled after panic");
+                    *this.pending = Some(Box::pin((this.call)(f, next)));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let pending = usize::from(self.pending.is_some());
+        let (lower, upper) = self.iter.async_size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncMapMut` that includes debug output for the
+/// underlying iterator. The mapping closure is not shown.
+impl<I, F, Fut> Debug for AsyncMapMut<I, F, Fut>
+where
+    I: AsyncIterator + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncMapMut")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
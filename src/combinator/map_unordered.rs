@@ -0,0 +1,165 @@
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that maps each item with an async function, keeping up to
+    /// `limit` of the resulting futures in flight at once and yielding their outputs as they
+    /// complete.
+    ///
+    /// Unlike [`AsyncMapBuffered`](super::map_buffered::AsyncMapBuffered), a slow future never
+    /// holds back the outputs of faster ones, so the order of the yielded items is not related to
+    /// the order of the underlying iterator.
+    ///
+    /// Like `AsyncMapBuffered`, it boxes each mapped future and polls all the futures in flight on
+    /// every wake-up, so a poll costs O(`limit`).
+    ///
+    /// This struct is created by the `.map_async_unordered()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous mapping function, which produces a future for each item.
+    /// - `Fut`: The future produced by the mapping function.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncMapUnordered<I, F, Fut>
+    where
+        Fut: Future,
+    {
+        #[pin]
+        iter: I,
+        f: F,
+        limit: usize,
+        in_flight: Vec<Pin<Box<Fut>>>,
+        ready: VecDeque<Fut::Output>,
+        exhausted: bool,
+    }
+}
+
+impl<I, F, Fut> AsyncMapUnordered<I, F, Fut>
+where
+    Fut: Future,
+{
+    /// Creates a new `AsyncMapUnordered` running at most `limit` mapped futures concurrently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    pub(crate) fn new(iter: I, limit: usize, f: F) -> Self {
+        assert!(limit > 0, "concurrency limit must be greater than zero");
+
+        Self {
+            iter,
+            f,
+            limit,
+            in_flight: Vec::with_capacity(limit),
+            ready: VecDeque::new(),
+            exhausted: false,
+        }
+    }
+}
+
+/// Polls every future in `in_flight`, moving the outputs of those that finish into `ready`.
+///
+/// All the futures are polled because they share the task's waker, which does not tell which one
+/// was woken.
+///
+/// Returns `Poll::Ready` once at least one output is available.
+fn poll_in_flight<Fut>(
+    in_flight: &mut Vec<Pin<Box<Fut>>>,
+    ready: &mut VecDeque<Fut::Output>,
+    cx: &mut Context<'_>,
+) -> Poll<()>
+where
+    Fut: Future,
+{
+    let mut index = 0;
+    while index < in_flight.len() {
+        match in_flight[index].as_mut().poll(cx) {
+            Poll::Ready(output) => {
+                drop(in_flight.swap_remove(index));
+                ready.push_back(output);
+            }
+            Poll::Pending => index += 1,
+        }
+    }
+
+    if ready.is_empty() {
+        Poll::Pending
+    } else {
+        Poll::Ready(())
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncMapUnordered`.
+///
+/// The `poll_next()` method keeps pulling items from the underlying iterator until `limit`
+/// futures are in flight, drives all of them concurrently, and yields whichever output is
+/// available first.
+impl<B, I, F, Fut> AsyncIterator for AsyncMapUnordered<I, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = B>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while !*this.exhausted && this.in_flight.len() < *this.limit {
+            match this.iter.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => this.in_flight.push(Box::pin((this.f)(item))),
+                Poll::Ready(None) => *this.exhausted = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if this.ready.is_empty() {
+            let _ = poll_in_flight(this.in_flight, this.ready, cx);
+        }
+
+        if let Some(output) = this.ready.pop_front() {
+            Poll::Ready(Some(output))
+        } else if *this.exhausted && this.in_flight.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.in_flight.len() + self.ready.len();
+        if self.exhausted {
+            return (pending, Some(pending));
+        }
+
+        let (lower, upper) = self.iter.async_size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncMapUnordered` that includes debug output for the
+/// underlying iterator. The mapping function and the futures in flight are not shown.
+impl<I, F, Fut> Debug for AsyncMapUnordered<I, F, Fut>
+where
+    I: AsyncIterator + Debug,
+    Fut: Future,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncMapUnordered")
+            .field("iter", &self.iter)
+            .field("limit", &self.limit)
+            .field("in_flight", &self.in_flight.len())
+            .finish()
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that maps items with an async function and yields the
+    /// `Some` values it resolves to, stopping at the first `None`.
+    ///
+    /// The mapping function is awaited exactly once per item. After it first resolves to `None`,
+    /// the adapter returns `None` without polling the underlying iterator again.
+    ///
+    /// This struct is created by the `.map_while_async()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous mapping function, which produces a future for each item.
+    /// - `Fut`: The future produced by the mapping function, resolving to an `Option`.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncMapWhile<I, F, Fut> {
+        #[pin]
+        iter: I,
+        f: F,
+        pending: Option<Pin<Box<Fut>>>,
+        done: bool,
+    }
+}
+
+impl<I, F, Fut> AsyncMapWhile<I, F, Fut> {
+    /// Creates a new `AsyncMapWhile` applying `f` to the items of `iter` until it resolves to
+    /// `None`.
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            pending: None,
+            done: false,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncMapWhile`.
+///
+/// The `poll_next()` method maps the next item from the underlying iterator and yields the value
+/// if there is one. Once the mapping function resolves to `None`, every following call returns
+/// `None`.
+impl<B, I, F, Fut> AsyncIterator for AsyncMapWhile<I, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Option<B>>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            if let Some(pending) = this.pending.as_mut() {
+                let output = ready!(pending.as_mut().poll(cx));
+                *this.pending = None;
+                *this.done = output.is_none();
+                return Poll::Ready(output);
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => *this.pending = Some(Box::pin((this.f)(next))),
+                None => {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let pending = usize::from(self.pending.is_some());
+        let (_, upper) = self.iter.async_size_hint();
+        (0, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncMapWhile` that includes debug output for the
+/// underlying iterator. The mapping function is not shown.
+impl<I, F, Fut> Debug for AsyncMapWhile<I, F, Fut>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncMapWhile")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .finish()
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that threads a state through an async function, yielding a
+    /// value per item until the function resolves to `None`.
+    ///
+    /// The state is moved into the future produced for each item, and handed back together with the
+    /// yielded value once it resolves to `Some((state, value))`. This lets the state be updated
+    /// from the result of async work. When the future resolves to `None`, the iteration ends and
+    /// the underlying iterator is not polled again.
+    ///
+    /// This struct is created by the `.scan_async()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `St`: The state threaded through the iteration.
+    /// - `F`: The asynchronous step function, which produces a future for the state and each item.
+    /// - `Fut`: The future produced by the step function.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncScan<I, St, F, Fut> {
+        #[pin]
+        iter: I,
+        f: F,
+        state: Option<St>,
+        pending: Option<Pin<Box<Fut>>>,
+    }
+}
+
+impl<I, St, F, Fut> AsyncScan<I, St, F, Fut> {
+    /// Creates a new `AsyncScan` starting from `initial_state` and applying `f` to every item of
+    /// `iter`.
+    pub(crate) fn new(iter: I, initial_state: St, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            state: Some(initial_state),
+            pending: None,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncScan`.
+///
+/// The `poll_next()` method pulls the next item from the underlying iterator, runs the step
+/// function on it with the current state, and yields the resulting value. Once the step function
+/// resolves to `None`, every following call returns `None`.
+impl<B, I, St, F, Fut> AsyncIterator for AsyncScan<I, St, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(St, I::Item) -> Fut,
+    Fut: Future<Output = Option<(St, B)>>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(pending) = this.pending.as_mut() {
+                let output = ready!(pending.as_mut().poll(cx));
+                *this.pending = None;
+                return Poll::Ready(output.map(|(state, value)| {
+                    *this.state = Some(state);
+                    value
+                }));
+            }
+
+            if this.state.is_none() {
+                return Poll::Ready(None);
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => {
+                    if let Some(state) = this.state.take() {
+                        *this.pending = Some(Box::pin((this.f)(state, next)));
+                    }
+                }
+                None => {
+                    *this.state = None;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        if self.pending.is_none() && self.state.is_none() {
+            return (0, Some(0));
+        }
+
+        let pending = usize::from(self.pending.is_some());
+        let (_, upper) = self.iter.async_size_hint();
+        (0, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncScan` that includes debug output for the
+/// underlying iterator and the current state. The step function is not shown, and the state is
+/// `None` while a step is running or after the iteration has ended.
+impl<I, St, F, Fut> Debug for AsyncScan<I, St, F, Fut>
+where
+    I: Debug,
+    St: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncScan")
+            .field("iter", &self.iter)
+            .field("state", &self.state)
+            .finish()
+    }
+}
@@ -0,0 +1,64 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that skips the first `n` items of the underlying iterator.
+    ///
+    /// The skipped items are pulled and dropped the first time an item is requested, so the adapter
+    /// does nothing until it is polled.
+    ///
+    /// This struct is created by the `.async_skip()` method on `AsyncIterTools`.
+    #[derive(Clone, Debug)]
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncSkip<I> {
+        #[pin]
+        iter: I,
+        remaining: usize,
+    }
+}
+
+impl<I> AsyncSkip<I> {
+    /// Creates a new `AsyncSkip` skipping the first `n` items of `iter`.
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self { iter, remaining: n }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncSkip`.
+///
+/// The `poll_next()` method first drops the items that are still to be skipped, and then yields
+/// items from the underlying iterator unchanged.
+impl<I> AsyncIterator for AsyncSkip<I>
+where
+    I: AsyncIterator,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while *this.remaining > 0 {
+            if ready!(this.iter.as_mut().poll_next(cx)).is_none() {
+                *this.remaining = 0;
+                return Poll::Ready(None);
+            }
+            *this.remaining -= 1;
+        }
+
+        this.iter.poll_next(cx)
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.async_size_hint();
+        (
+            lower.saturating_sub(self.remaining),
+            upper.map(|upper| upper.saturating_sub(self.remaining)),
+        )
+    }
+}
@@ -0,0 +1,114 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that skips items while an async predicate, given a
+    /// reference to each item, resolves to `true`, and yields every item from the first rejected
+    /// one onwards.
+    ///
+    /// The predicate is awaited exactly once per item until it first resolves to `false`, and is
+    /// not called again after that.
+    ///
+    /// This struct is created by the `.skip_while_async()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous predicate, which produces a future for each borrowed item.
+    /// - `Fut`: The future produced by the predicate.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncSkipWhile<I, F, Fut>
+    where
+        I: AsyncIterator,
+    {
+        #[pin]
+        iter: I,
+        f: F,
+        pending: Option<(I::Item, Pin<Box<Fut>>)>,
+        skipping: bool,
+    }
+}
+
+impl<I, F, Fut> AsyncSkipWhile<I, F, Fut>
+where
+    I: AsyncIterator,
+{
+    /// Creates a new `AsyncSkipWhile` skipping the items of `iter` until `f` resolves to `false`.
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            pending: None,
+            skipping: true,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncSkipWhile`.
+///
+/// The `poll_next()` method drops items from the underlying iterator while the predicate accepts
+/// them, and then yields the remaining items unchanged.
+impl<I, F, Fut> AsyncIterator for AsyncSkipWhile<I, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(&I::Item) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while *this.skipping {
+            if let Some((_, predicate)) = this.pending.as_mut() {
+                let skip = ready!(predicate.as_mut().poll(cx));
+                let pending = this.pending.take();
+                if !skip {
+                    *this.skipping = false;
+                    return Poll::Ready(pending.map(|(next, _)| next));
+                }
+                continue;
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => {
+                    let predicate = Box::pin((this.f)(&next));
+                    *this.pending = Some((next, predicate));
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+
+        this.iter.poll_next(cx)
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.async_size_hint();
+        if !self.skipping {
+            return (lower, upper);
+        }
+
+        let pending = usize::from(self.pending.is_some());
+        (0, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncSkipWhile` that includes debug output for the
+/// underlying iterator. The predicate is not shown.
+impl<I, F, Fut> Debug for AsyncSkipWhile<I, F, Fut>
+where
+    I: AsyncIterator + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncSkipWhile")
+            .field("iter", &self.iter)
+            .field("skipping", &self.skipping)
+            .finish()
+    }
+}
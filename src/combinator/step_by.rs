@@ -0,0 +1,81 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that yields the first item of the underlying iterator and
+    /// then every `step`th item after it.
+    ///
+    /// The items in between are pulled and dropped, and the underlying iterator is never polled for
+    /// items past the last one that is yielded.
+    ///
+    /// This struct is created by the `.async_step_by()` method on `AsyncIterTools`.
+    #[derive(Clone, Debug)]
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncStepBy<I> {
+        #[pin]
+        iter: I,
+        step_minus_one: usize,
+        to_skip: usize,
+    }
+}
+
+impl<I> AsyncStepBy<I> {
+    /// Creates a new `AsyncStepBy` yielding every `step`th item of `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub(crate) fn new(iter: I, step: usize) -> Self {
+        assert!(step != 0, "step must be greater than zero");
+
+        Self {
+            iter,
+            step_minus_one: step - 1,
+            to_skip: 0,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncStepBy`.
+///
+/// The `poll_next()` method drops `step - 1` items of the underlying iterator between every two
+/// items it yields.
+impl<I> AsyncIterator for AsyncStepBy<I>
+where
+    I: AsyncIterator,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while *this.to_skip > 0 {
+            if ready!(this.iter.as_mut().poll_next(cx)).is_none() {
+                *this.to_skip = 0;
+                return Poll::Ready(None);
+            }
+            *this.to_skip -= 1;
+        }
+
+        let next = ready!(this.iter.poll_next(cx));
+        if next.is_some() {
+            *this.to_skip = *this.step_minus_one;
+        }
+        Poll::Ready(next)
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let step = self.step_minus_one + 1;
+        let to_skip = self.to_skip;
+        let remaining = move |n: usize| n.saturating_sub(to_skip).div_ceil(step);
+
+        let (lower, upper) = self.iter.async_size_hint();
+        (remaining(lower), upper.map(remaining))
+    }
+}
@@ -0,0 +1,69 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that yields at most `n` items of the underlying iterator.
+    ///
+    /// Once `n` items have been yielded, the underlying iterator is not polled again, so no async
+    /// work behind it is started for items that would be discarded.
+    ///
+    /// This struct is created by the `.async_take()` method on `AsyncIterTools`.
+    #[derive(Clone, Debug)]
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncTake<I> {
+        #[pin]
+        iter: I,
+        remaining: usize,
+    }
+}
+
+impl<I> AsyncTake<I> {
+    /// Creates a new `AsyncTake` yielding at most `n` items of `iter`.
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self { iter, remaining: n }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncTake`.
+///
+/// The `poll_next()` method yields items from the underlying iterator until `n` of them have
+/// been yielded, and returns `None` from then on without polling it.
+impl<I> AsyncIterator for AsyncTake<I>
+where
+    I: AsyncIterator,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if *this.remaining == 0 {
+            return Poll::Ready(None);
+        }
+
+        let next = ready!(this.iter.poll_next(cx));
+        *this.remaining = if next.is_some() {
+            *this.remaining - 1
+        } else {
+            0
+        };
+        Poll::Ready(next)
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        if self.remaining == 0 {
+            return (0, Some(0));
+        }
+
+        let (lower, upper) = self.iter.async_size_hint();
+        (
+            lower.min(self.remaining),
+            Some(upper.map_or(self.remaining, |upper| upper.min(self.remaining))),
+        )
+    }
+}
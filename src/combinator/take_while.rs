@@ -0,0 +1,117 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that yields items while an async predicate, given a
+    /// reference to each item, resolves to `true`.
+    ///
+    /// The predicate is awaited exactly once per item. The first item it rejects is dropped, and
+    /// the adapter then returns `None` without polling the underlying iterator again.
+    ///
+    /// This struct is created by the `.take_while_async()` method on `AsyncIterTools`.
+    ///
+    /// # Type Parameters
+    /// - `I`: The underlying async iterator.
+    /// - `F`: The asynchronous predicate, which produces a future for each borrowed item.
+    /// - `Fut`: The future produced by the predicate.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncTakeWhile<I, F, Fut>
+    where
+        I: AsyncIterator,
+    {
+        #[pin]
+        iter: I,
+        f: F,
+        pending: Option<(I::Item, Pin<Box<Fut>>)>,
+        done: bool,
+    }
+}
+
+impl<I, F, Fut> AsyncTakeWhile<I, F, Fut>
+where
+    I: AsyncIterator,
+{
+    /// Creates a new `AsyncTakeWhile` yielding the items of `iter` until `f` resolves to `false`.
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            f,
+            pending: None,
+            done: false,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncTakeWhile`.
+///
+/// The `poll_next()` method pulls the next item from the underlying iterator and yields it if the
+/// predicate accepts it. Once an item is rejected, every following call returns `None`.
+impl<I, F, Fut> AsyncIterator for AsyncTakeWhile<I, F, Fut>
+where
+    I: AsyncIterator,
+    F: FnMut(&I::Item) -> Fut,
+    Fut: Future<Output = bool>,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            if let Some((_, predicate)) = this.pending.as_mut() {
+                let keep = ready!(predicate.as_mut().poll(cx));
+                let pending = this.pending.take();
+                if keep {
+                    return Poll::Ready(pending.map(|(next, _)| next));
+                }
+                *this.done = true;
+                return Poll::Ready(None);
+            }
+
+            match ready!(this.iter.as_mut().poll_next(cx)) {
+                Some(next) => {
+                    let predicate = Box::pin((this.f)(&next));
+                    *this.pending = Some((next, predicate));
+                }
+                None => {
+                    *this.done = true;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let pending = usize::from(self.pending.is_some());
+        let (_, upper) = self.iter.async_size_hint();
+        (0, upper.and_then(|upper| upper.checked_add(pending)))
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncTakeWhile` that includes debug output for the
+/// underlying iterator. The predicate is not shown.
+impl<I, F, Fut> Debug for AsyncTakeWhile<I, F, Fut>
+where
+    I: AsyncIterator + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncTakeWhile")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .finish()
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use crate::iter::AsyncIterator;
+
+pin_project! {
+    /// An asynchronous iterator adapter that pairs up the items of two iterators, stopping as soon
+    /// as either of them is exhausted.
+    ///
+    /// Both iterators are polled at the same time, so a slow item on one side does not delay
+    /// pulling the matching item from the other. An item that is ready before its partner is kept
+    /// until the pair is complete.
+    ///
+    /// This struct is created by the `.async_zip()` method on `AsyncIterTools`.
+    #[must_use = "async iterator combinators are lazy and do nothing unless consumed"]
+    pub struct AsyncZip<A, B>
+    where
+        A: AsyncIterator,
+        B: AsyncIterator,
+    {
+        #[pin]
+        a: A,
+        #[pin]
+        b: B,
+        a_item: Option<A::Item>,
+        b_item: Option<B::Item>,
+    }
+}
+
+impl<A, B> AsyncZip<A, B>
+where
+    A: AsyncIterator,
+    B: AsyncIterator,
+{
+    /// Creates a new `AsyncZip` pairing the items of `a` with those of `b`.
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self {
+            a,
+            b,
+            a_item: None,
+            b_item: None,
+        }
+    }
+}
+
+/// Implements the `AsyncIterator` trait for `AsyncZip`.
+///
+/// The `poll_next()` method pulls the next item from both iterators concurrently and yields them
+/// as a pair, or returns `None` once either iterator is exhausted.
+impl<A, B> AsyncIterator for AsyncZip<A, B>
+where
+    A: AsyncIterator,
+    B: AsyncIterator,
+{
+    type Item = (A::Item, B::Item);
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if this.a_item.is_none() {
+            match this.a.poll_next(cx) {
+                Poll::Ready(Some(item)) => *this.a_item = Some(item),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => {}
+            }
+        }
+
+        if this.b_item.is_none() {
+            match this.b.poll_next(cx) {
+                Poll::Ready(Some(item)) => *this.b_item = Some(item),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => {}
+            }
+        }
+
+        match (this.a_item.take(), this.b_item.take()) {
+            (Some(a), Some(b)) => Poll::Ready(Some((a, b))),
+            (a_item, b_item) => {
+                *this.a_item = a_item;
+                *this.b_item = b_item;
+                Poll::Pending
+            }
+        }
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let with_buffered = |(lower, upper): (usize, Option<usize>), buffered: bool| {
+            let buffered = usize::from(buffered);
+            (
+                lower.saturating_add(buffered),
+                upper.and_then(|upper| upper.checked_add(buffered)),
+            )
+        };
+
+        let (a_lower, a_upper) = with_buffered(self.a.async_size_hint(), self.a_item.is_some());
+        let (b_lower, b_upper) = with_buffered(self.b.async_size_hint(), self.b_item.is_some());
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (upper, None) | (None, upper) => upper,
+        };
+        (a_lower.min(b_lower), upper)
+    }
+}
+
+/// Provides a `Debug` implementation for `AsyncZip` that includes debug output for both
+/// underlying iterators.
+impl<A, B> Debug for AsyncZip<A, B>
+where
+    A: AsyncIterator + Debug,
+    B: AsyncIterator + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncZip")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
@@ -1,9 +1,23 @@
-use std::vec::IntoIter;
+use std::{
+    future,
+    pin::{Pin, pin},
+    task::{Context, Poll},
+    vec::IntoIter,
+};
 
 use sync_iter::SyncIter;
 
+pub mod from_iter;
+pub mod poll_fn;
 pub mod process_result;
 pub mod sync_iter;
+pub mod try_output;
+pub mod unfold;
+
+pub use from_iter::{Iter, iter};
+pub use poll_fn::{PollFn, poll_fn};
+pub use try_output::TryOutput;
+pub use unfold::{Unfold, unfold};
 
 /// Trait for asynchronous iteration.
 ///
@@ -11,27 +25,72 @@ pub mod sync_iter;
 /// contexts where `next()` returns a `Future`. It provides core methods for driving async iteration,
 /// collecting results, and converting to a synchronous iterator.
 ///
-/// Implementors must define `next_async`, which yields the next item wrapped in a `Future`.
+/// Implementors must define [`poll_next`](AsyncIterator::poll_next), which attempts to pull the
+/// next item and registers the current task for wakeup if it is not yet available. Any work that is
+/// in progress between two polls, such as a future awaited to produce the next item, has to be
+/// stored in the iterator itself. [`next_async`](AsyncIterator::next_async) is provided on top of
+/// it for `Unpin` iterators, and simple sources can be built without a new type through
+/// [`poll_fn()`] or [`iter()`]. [`unfold()`] turns an async closure into an async iterator, so
+/// `async` logic does not need a hand-written state machine.
 pub trait AsyncIterator {
     /// The type of the elements being iterated over.
     type Item;
 
+    /// Attempts to pull the next item out of the iterator, registering the current task for
+    /// wakeup if it is not yet available.
+    ///
+    /// Returns `Poll::Ready(None)` when iteration is finished. This is the poll-based core of the
+    /// trait, which every combinator in this crate uses to drive the iterator it wraps, and which
+    /// can be called from a hand-written `Future::poll` to keep an async iterator in a struct
+    /// across polls.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{
+    ///     pin::Pin,
+    ///     task::{Context, Poll, Waker},
+    /// };
+    ///
+    /// use async_iter_ext::{AsyncIterator, iter::iter};
+    ///
+    /// let mut iter = iter([1, 2]);
+    /// let mut cx = Context::from_waker(Waker::noop());
+    ///
+    /// assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Ready(Some(1)));
+    /// assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Ready(Some(2)));
+    /// assert_eq!(Pin::new(&mut iter).poll_next(&mut cx), Poll::Ready(None));
+    /// ```
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>>;
+
     /// Asynchronously returns the next item in the iterator.
     ///
-    /// Returns `None` when iteration is finished.
+    /// Returns `None` when iteration is finished. The returned future polls
+    /// [`poll_next`](AsyncIterator::poll_next) until an item is ready, so dropping it before it
+    /// completes does not lose any work that the iterator has in progress.
+    ///
+    /// Iterators that are not `Unpin` can be pinned first, for example with `std::pin::pin!`, and
+    /// then driven with `std::future::poll_fn(|cx| iter.as_mut().poll_next(cx))`.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use async_iter_ext::AsyncIterator;
     ///
-    /// async fn iterate<I: AsyncIterator>(mut iter: I) {
+    /// async fn iterate<I: AsyncIterator + Unpin>(mut iter: I) {
     ///     while let Some(item) = iter.next_async().await {
     ///         // process item
     ///     }
     /// }
     /// ```
-    fn next_async(&mut self) -> impl Future<Output = Option<Self::Item>>;
+    fn next_async(&mut self) -> impl Future<Output = Option<Self::Item>>
+    where
+        Self: Unpin,
+    {
+        future::poll_fn(move |cx| Pin::new(&mut *self).poll_next(cx))
+    }
 
     /// Converts this async iterator into a synchronous [`SyncIter`] using `async` collection.
     ///
@@ -50,7 +109,7 @@ pub trait AsyncIterator {
     /// ```rust
     /// use async_iter_ext::AsyncIterator;
     ///
-    /// async fn convert<I: AsyncIterator>(iter: &mut I) {
+    /// async fn convert<I: AsyncIterator + Unpin>(iter: &mut I) {
     ///     let sync = iter.sync_iter().await;
     ///     for item in sync {
     ///         // use item
@@ -59,9 +118,9 @@ pub trait AsyncIterator {
     /// ```
     fn sync_iter(&mut self) -> impl Future<Output = SyncIter<IntoIter<Self::Item>>>
     where
-        Self: Sized,
+        Self: Sized + Unpin,
     {
-        async move { SyncIter::new(collect_into_vec(self).await.into_iter()) }
+        async move { SyncIter::new(collect_into_vec(Pin::new(self)).await.into_iter()) }
     }
 
     /// Provides a hint about the size of the remaining items.
@@ -93,40 +152,42 @@ pub trait AsyncIterator {
     ///     iter.async_collect::<Vec<_>>().await
     /// }
     /// ```
-    fn async_collect<B>(mut self) -> impl Future<Output = B>
+    fn async_collect<B>(self) -> impl Future<Output = B>
     where
         Self: Sized,
         B: FromIterator<Self::Item>,
     {
         async move {
-            let items = collect_into_vec(&mut self).await;
+            let items = collect_into_vec(pin!(self)).await;
             B::from_iter(items)
         }
     }
 }
 
+/// Returns a future that resolves to the next item of a pinned async iterator.
+///
+/// This is the counterpart of [`AsyncIterator::next_async`] for iterators that are not `Unpin`,
+/// used by the methods that take an iterator by value and pin it themselves.
+pub(crate) fn next_pinned<I>(mut iter: Pin<&mut I>) -> impl Future<Output = Option<I::Item>>
+where
+    I: AsyncIterator + ?Sized,
+{
+    future::poll_fn(move |cx| iter.as_mut().poll_next(cx))
+}
+
 /// Asynchronously collects all items from an [`AsyncIterator`] into a `Vec`.
 ///
-/// Uses `async_size_hint` to optimize preallocation if the upper bound is known.
-async fn collect_into_vec<I>(iter: &mut I) -> Vec<I::Item>
+/// Uses the lower bound of `async_size_hint` to preallocate capacity, and keeps pulling items
+/// until the iterator returns `None`.
+async fn collect_into_vec<I>(mut iter: Pin<&mut I>) -> Vec<I::Item>
 where
     I: AsyncIterator,
 {
-    let mut items = vec![];
-
-    match iter.async_size_hint() {
-        (_, Some(upper_limit)) => {
-            for _ in 0..upper_limit {
-                if let Some(item) = iter.next_async().await {
-                    items.push(item);
-                }
-            }
-        }
-        _ => {
-            while let Some(item) = iter.next_async().await {
-                items.push(item);
-            }
-        }
+    let (lower_limit, _) = iter.async_size_hint();
+    let mut items = Vec::with_capacity(lower_limit);
+
+    while let Some(item) = next_pinned(iter.as_mut()).await {
+        items.push(item);
     }
 
     items
@@ -136,14 +197,28 @@ where
 ///
 /// This enables any standard iterator to be used as an async iterator by immediately
 /// returning the next item.
+///
+/// The implementation is enabled by the default `blanket-iterator` feature. Disabling it makes it
+/// possible to implement `AsyncIterator` by hand for types that also implement `Iterator`, while
+/// standard iterators remain usable through [`iter()`].
+///
+/// The feature is not additive. Cargo unifies features across the dependency graph, so a single
+/// crate depending on this one with default features turns the blanket implementation on for
+/// every other crate as well, and their hand-written implementations for `Iterator` types stop
+/// compiling. Library authors should therefore depend on this crate with
+/// `default-features = false` and convert standard iterators with [`iter()`].
+///
+/// Only `Unpin` iterators are covered, since calling `Iterator::next` needs a plain mutable
+/// reference. Iterators that are not `Unpin` can be wrapped with [`iter()`] instead.
+#[cfg(feature = "blanket-iterator")]
 impl<T> AsyncIterator for T
 where
-    T: Iterator + ?Sized,
+    T: Iterator + Unpin + ?Sized,
 {
     type Item = T::Item;
 
-    async fn next_async(&mut self) -> Option<Self::Item> {
-        self.next()
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().next())
     }
 
     fn async_size_hint(&self) -> (usize, Option<usize>) {
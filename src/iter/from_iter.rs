@@ -0,0 +1,81 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::iter::AsyncIterator;
+
+/// Converts a synchronous iterator into an async iterator that yields its items immediately.
+///
+/// This works whether or not the `blanket-iterator` feature is enabled, which makes it the way to
+/// use a standard iterator as an async iterator in crates that disable the blanket implementation
+/// in order to implement [`AsyncIterator`] by hand.
+///
+/// ---
+///
+/// # Examples
+///
+/// ```rust
+/// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+/// use async_std::task;
+///
+/// task::block_on(async {
+///   let doubled = iter(vec![1, 2, 3])
+///     .map_async(|item| async move { item * 2 })
+///     .async_collect::<Vec<_>>()
+///     .await;
+///
+///   assert_eq!(doubled, vec![2, 4, 6]);
+/// });
+/// ```
+pub fn iter<I>(iter: I) -> Iter<I::IntoIter>
+where
+    I: IntoIterator,
+{
+    Iter {
+        iter: iter.into_iter(),
+    }
+}
+
+/// An async iterator over the items of a synchronous iterator.
+///
+/// This struct is created by the [`iter()`] function.
+#[derive(Clone)]
+#[must_use = "async iterators are lazy and do nothing unless consumed"]
+pub struct Iter<I> {
+    iter: I,
+}
+
+impl<I> Iter<I> {
+    /// Consumes the adapter and returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I> Unpin for Iter<I> {}
+
+impl<I> AsyncIterator for Iter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().iter.next())
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> Debug for Iter<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Iter").field("iter", &self.iter).finish()
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::iter::AsyncIterator;
+
+/// Creates an async iterator that yields the items produced by polling the given closure.
+///
+/// The closure is called with the task context every time the next item is requested, and works
+/// like [`AsyncIterator::poll_next`]: it returns `Poll::Pending` after arranging for the task to be
+/// woken, `Poll::Ready(Some(item))` to yield an item, or `Poll::Ready(None)` once it is finished.
+///
+/// ---
+///
+/// # Examples
+///
+/// ```rust
+/// use std::task::Poll;
+///
+/// use async_iter_ext::{AsyncIterator, iter::poll_fn};
+/// use async_std::task;
+///
+/// task::block_on(async {
+///   let mut count = 0;
+///   let counter = poll_fn(move |_cx| {
+///     count += 1;
+///     Poll::Ready((count <= 3).then_some(count))
+///   });
+///
+///   assert_eq!(counter.async_collect::<Vec<_>>().await, vec![1, 2, 3]);
+/// });
+/// ```
+pub fn poll_fn<T, F>(f: F) -> PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<Option<T>>,
+{
+    PollFn { f }
+}
+
+/// An async iterator driven by a closure that polls for the next item.
+///
+/// This struct is created by the [`poll_fn`] function.
+#[must_use = "async iterators are lazy and do nothing unless consumed"]
+pub struct PollFn<F> {
+    f: F,
+}
+
+impl<F> Unpin for PollFn<F> {}
+
+impl<T, F> AsyncIterator for PollFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<Option<T>>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        (self.get_mut().f)(cx)
+    }
+}
+
+impl<F> Debug for PollFn<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollFn").finish()
+    }
+}
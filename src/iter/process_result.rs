@@ -1,92 +1,268 @@
 use std::{
-    fmt::{Debug, Formatter},
+    error::Error,
+    fmt::{Debug, Display, Formatter},
+    marker::PhantomData,
+    mem,
     ops::Deref,
-    pin::{Pin, pin},
+    pin::Pin,
     task::{Context, Poll},
 };
 
+use pin_project_lite::pin_project;
+
 use crate::AsyncIterator;
 
 /// Defines the strategy to use when processing results from an asynchronous iterator.
 #[derive(Default, Clone, Debug)]
+#[non_exhaustive]
 pub enum ProcessResultsStrategy {
     /// Continue processing all results, separating successes and errors.
     #[default]
     Partition,
 
     /// Stop processing at the first error encountered.
+    ///
+    /// Successes received before the error are discarded.
     BreakOnError,
+
+    /// Stop processing at the first error encountered, keeping the successes received before it.
+    ///
+    /// This is useful to know which items were already handled when resuming an interrupted
+    /// batch.
+    BreakOnErrorKeepSuccesses,
+
+    /// Continue processing while separating successes and errors, but stop once the given number
+    /// of errors has been encountered.
+    ///
+    /// The number of errors must be greater than zero; use `BreakOnErrorKeepSuccesses` to stop at
+    /// the first error.
+    MaxErrors(usize),
+
+    /// Continue processing while separating successes and errors, but stop once the share of
+    /// errors exceeds `max_ratio`.
+    ///
+    /// The ratio is checked after every result once at least `min_samples` results have been
+    /// received, so a single early error does not stop the processing.
+    MaxErrorRatio {
+        /// The highest tolerated share of errors, between `0.0` and `1.0` inclusive.
+        max_ratio: f64,
+        /// The number of results to receive before the ratio is checked.
+        min_samples: usize,
+    },
+}
+
+impl ProcessResultsStrategy {
+    /// Returns the reason to stop processing after a result, given the number of successes and
+    /// errors received so far, or `None` if processing should continue.
+    ///
+    /// This is checked after successes as well as errors, since a success can be the result that
+    /// brings the sample count up to `min_samples` for `MaxErrorRatio`.
+    fn stop_reason(&self, successes: usize, errors: usize) -> Option<ProcessResultsStopReason> {
+        match *self {
+            ProcessResultsStrategy::Partition => None,
+            ProcessResultsStrategy::BreakOnError
+            | ProcessResultsStrategy::BreakOnErrorKeepSuccesses => {
+                (errors > 0).then_some(ProcessResultsStopReason::FirstError)
+            }
+            ProcessResultsStrategy::MaxErrors(max_errors) => {
+                (errors >= max_errors).then_some(ProcessResultsStopReason::MaxErrors)
+            }
+            ProcessResultsStrategy::MaxErrorRatio {
+                max_ratio,
+                min_samples,
+            } => {
+                let samples = successes + errors;
+                (samples >= min_samples && errors as f64 / samples as f64 > max_ratio)
+                    .then_some(ProcessResultsStopReason::MaxErrorRatio)
+            }
+        }
+    }
+}
+
+/// Describes why processing of the results stopped.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ProcessResultsStopReason {
+    /// The iterator was exhausted.
+    #[default]
+    Exhausted,
+
+    /// An error was encountered with `BreakOnError` or `BreakOnErrorKeepSuccesses`.
+    FirstError,
+
+    /// The error count of `MaxErrors` was reached.
+    MaxErrors,
+
+    /// The error ratio of `MaxErrorRatio` was exceeded.
+    MaxErrorRatio,
 }
 
 /// A container that holds both successful and erroneous results.
-pub struct ProcessResultsContainer<T, E> {
-    successes: Vec<T>,
-    errors: Vec<E>,
+///
+/// Successes and errors are collected into `Vec`s by default, and into any other
+/// `Default + Extend` collections chosen with [`ProcessResults::with_collections`].
+///
+/// When index tracking is enabled with [`ProcessResults::with_indices`], the container also
+/// records the position in the source iterator of every success and error.
+pub struct ProcessResultsContainer<T, E, S = Vec<T>, C = Vec<E>> {
+    successes: S,
+    errors: C,
+    success_indices: Vec<usize>,
+    error_indices: Vec<usize>,
+    stop_reason: ProcessResultsStopReason,
+    _marker: PhantomData<fn() -> (T, E)>,
 }
 
-impl<T, E> Deref for ProcessResultsContainer<T, E> {
-    type Target = Vec<T>;
+impl<T, E, S, C> Deref for ProcessResultsContainer<T, E, S, C> {
+    type Target = S;
 
-    /// Dereferences to the vector of successful results.
+    /// Dereferences to the collection of successful results.
     fn deref(&self) -> &Self::Target {
         self.successes()
     }
 }
 
-impl<T, E> ProcessResultsContainer<T, E> {
+impl<T, E, S, C> ProcessResultsContainer<T, E, S, C> {
     /// Converts the container into a `Result`, returning `Ok` with successes,
     /// or the first `Err` if there are any errors.
-    pub fn into_result(self) -> Result<Vec<T>, E> {
-        if !self.errors.is_empty() {
-            Err(self.into_errors().remove(0))
-        } else {
-            Ok(self.successes)
+    pub fn into_result(self) -> Result<S, E>
+    where
+        C: IntoIterator<Item = E>,
+    {
+        match self.errors.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(self.successes),
         }
     }
 
-    /// Consumes the container and returns the vector of successes.
-    pub fn into_successes(self) -> Vec<T> {
+    /// Converts the container into a `Result`, returning `Ok` with successes,
+    /// or a [`ProcessResultsError`] holding every error if there are any.
+    ///
+    /// This is the same as converting the container with `Result::from`.
+    pub fn into_aggregate_result(self) -> Result<S, ProcessResultsError<E>>
+    where
+        C: IntoIterator<Item = E>,
+    {
+        self.into()
+    }
+
+    /// Consumes the container and returns the collection of successes.
+    pub fn into_successes(self) -> S {
         self.successes
     }
 
-    /// Consumes the container and returns the vector of errors.
-    pub fn into_errors(self) -> Vec<E> {
+    /// Consumes the container and returns the collection of errors.
+    pub fn into_errors(self) -> C {
         self.errors
     }
 
-    /// Returns a reference to the vector of successes.
-    pub fn successes(&self) -> &Vec<T> {
-        self.successes.as_ref()
+    /// Returns a reference to the collection of successes.
+    pub fn successes(&self) -> &S {
+        &self.successes
     }
 
-    /// Returns a reference to the vector of errors.
-    pub fn errors(&self) -> &Vec<E> {
-        self.errors.as_ref()
+    /// Returns a reference to the collection of errors.
+    pub fn errors(&self) -> &C {
+        &self.errors
+    }
+
+    /// Returns the positions in the source iterator of the successes, in the order they were
+    /// received.
+    ///
+    /// This is empty unless index tracking was enabled with [`ProcessResults::with_indices`], and
+    /// only lines up with [`successes`](ProcessResultsContainer::successes) when the successes
+    /// collection keeps every item in order.
+    pub fn success_indices(&self) -> &[usize] {
+        &self.success_indices
+    }
+
+    /// Returns the positions in the source iterator of the errors, in the order they were
+    /// received.
+    ///
+    /// This is empty unless index tracking was enabled with [`ProcessResults::with_indices`], and
+    /// only lines up with [`errors`](ProcessResultsContainer::errors) when the errors collection
+    /// keeps every item in order.
+    pub fn error_indices(&self) -> &[usize] {
+        &self.error_indices
+    }
+
+    /// Returns why processing stopped.
+    pub fn stop_reason(&self) -> ProcessResultsStopReason {
+        self.stop_reason
+    }
+
+    /// Sets why processing stopped.
+    fn with_stop_reason(mut self, stop_reason: ProcessResultsStopReason) -> Self {
+        self.stop_reason = stop_reason;
+        self
     }
 }
 
-impl<T, E> Debug for ProcessResultsContainer<T, E>
+impl<T, E> ProcessResultsContainer<T, E> {
+    /// Returns an iterator over the successes paired with their positions in the source iterator.
+    ///
+    /// This is empty unless index tracking was enabled with [`ProcessResults::with_indices`].
+    pub fn indexed_successes(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.success_indices
+            .iter()
+            .copied()
+            .zip(self.successes.iter())
+    }
+
+    /// Returns an iterator over the errors paired with their positions in the source iterator.
+    ///
+    /// This is empty unless index tracking was enabled with [`ProcessResults::with_indices`].
+    pub fn indexed_errors(&self) -> impl Iterator<Item = (usize, &E)> {
+        self.error_indices.iter().copied().zip(self.errors.iter())
+    }
+}
+
+impl<T, E, S, C> Default for ProcessResultsContainer<T, E, S, C>
+where
+    S: Default,
+    C: Default,
+{
+    fn default() -> Self {
+        Self {
+            successes: S::default(),
+            errors: C::default(),
+            success_indices: vec![],
+            error_indices: vec![],
+            stop_reason: ProcessResultsStopReason::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, E, S, C> Debug for ProcessResultsContainer<T, E, S, C>
 where
-    T: Debug,
-    E: Debug,
+    S: Debug,
+    C: Debug,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ProcessResultsContainer")
             .field("successes", &self.successes)
             .field("errors", &self.errors)
+            .field("success_indices", &self.success_indices)
+            .field("error_indices", &self.error_indices)
+            .field("stop_reason", &self.stop_reason)
             .finish()
     }
 }
 
-impl<T, E> Clone for ProcessResultsContainer<T, E>
+impl<T, E, S, C> Clone for ProcessResultsContainer<T, E, S, C>
 where
-    T: Clone,
-    E: Clone,
+    S: Clone,
+    C: Clone,
 {
     fn clone(&self) -> Self {
         Self {
             successes: self.successes.clone(),
             errors: self.errors.clone(),
+            success_indices: self.success_indices.clone(),
+            error_indices: self.error_indices.clone(),
+            stop_reason: self.stop_reason,
+            _marker: PhantomData,
         }
     }
 }
@@ -94,18 +270,100 @@ where
 impl<T, E> From<(Vec<T>, Vec<E>)> for ProcessResultsContainer<T, E> {
     /// Creates a `ProcessResultsContainer` from a tuple of successes and errors.
     fn from((successes, errors): (Vec<T>, Vec<E>)) -> Self {
-        Self { successes, errors }
+        Self {
+            successes,
+            errors,
+            ..Self::default()
+        }
     }
 }
 
-/// A future that processes results from an asynchronous iterator,
-/// collecting successes and errors based on the specified strategy.
-pub struct ProcessResults<I, T, E>
+impl<T, E, S, C> From<ProcessResultsContainer<T, E, S, C>> for Result<S, ProcessResultsError<E>>
 where
-    I: AsyncIterator<Item = Result<T, E>>,
+    C: IntoIterator<Item = E>,
 {
-    iter: I,
-    strategy: ProcessResultsStrategy,
+    /// Converts the container into `Ok` with the successes if there are no errors, or into `Err`
+    /// with every error otherwise.
+    fn from(container: ProcessResultsContainer<T, E, S, C>) -> Self {
+        let errors = container.errors.into_iter().collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(container.successes)
+        } else {
+            Err(ProcessResultsError { errors })
+        }
+    }
+}
+
+/// An error holding every error collected while processing results.
+///
+/// This is created by [`ProcessResultsContainer::into_aggregate_result`], and always holds at
+/// least one error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessResultsError<E> {
+    errors: Vec<E>,
+}
+
+impl<E> ProcessResultsError<E> {
+    /// Returns a reference to the vector of errors.
+    pub fn errors(&self) -> &Vec<E> {
+        self.errors.as_ref()
+    }
+
+    /// Consumes the aggregate error and returns the vector of errors.
+    pub fn into_errors(self) -> Vec<E> {
+        self.errors
+    }
+}
+
+impl<E> Display for ProcessResultsError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.errors.as_slice() {
+            [error] => write!(f, "1 error occurred while processing results: {error}"),
+            errors => {
+                write!(
+                    f,
+                    "{} errors occurred while processing results: ",
+                    errors.len()
+                )?;
+                for (index, error) in errors.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str("; ")?;
+                    }
+                    write!(f, "{error}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The aggregate error has no `source()`, since its `Display` output already includes every
+/// collected error. Use [`errors()`](ProcessResultsError::errors) to inspect them individually.
+/// Only requires the collected errors to be displayable, so an aggregate of plain messages such as
+/// `&str` can be returned as a `Box<dyn Error>` too.
+impl<E> Error for ProcessResultsError<E> where E: Debug + Display {}
+
+pin_project! {
+    /// A future that processes results from an asynchronous iterator, collecting successes and
+    /// errors based on the specified strategy.
+    ///
+    /// Items are pulled from the iterator one at a time, and the iterator is not polled again once
+    /// the strategy decides to stop, so any async work behind the remaining items never runs.
+    pub struct ProcessResults<I, T, E, S = Vec<T>, C = Vec<E>>
+    where
+        I: AsyncIterator<Item = Result<T, E>>,
+    {
+        #[pin]
+        iter: I,
+        strategy: ProcessResultsStrategy,
+        track_indices: bool,
+        success_count: usize,
+        error_count: usize,
+        results: ProcessResultsContainer<T, E, S, C>,
+    }
 }
 
 impl<I, T, E> ProcessResults<I, T, E>
@@ -117,62 +375,142 @@ where
         Self {
             iter,
             strategy: ProcessResultsStrategy::default(),
+            track_indices: false,
+            success_count: 0,
+            error_count: 0,
+            results: ProcessResultsContainer::default(),
         }
     }
+}
 
+impl<I, T, E, S, C> ProcessResults<I, T, E, S, C>
+where
+    I: AsyncIterator<Item = Result<T, E>>,
+{
     /// Sets the processing strategy to use for handling errors during iteration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strategy is `MaxErrors(0)`, or `MaxErrorRatio` with a `max_ratio` that is
+    /// NaN or outside of `0.0..=1.0`.
     pub fn with_process_strategy(mut self, strategy: ProcessResultsStrategy) -> Self {
+        match strategy {
+            ProcessResultsStrategy::MaxErrors(max_errors) => {
+                assert!(max_errors > 0, "max errors must be greater than zero");
+            }
+            ProcessResultsStrategy::MaxErrorRatio { max_ratio, .. } => {
+                assert!(
+                    (0.0..=1.0).contains(&max_ratio),
+                    "max error ratio must be between 0.0 and 1.0"
+                );
+            }
+            _ => {}
+        }
         self.strategy = strategy;
         self
     }
+
+    /// Records the position in the source iterator of every success and error, making them
+    /// available through [`ProcessResultsContainer::success_indices`] and
+    /// [`ProcessResultsContainer::error_indices`].
+    ///
+    /// A position is recorded for every result received, so the indices only line up with the
+    /// successes and errors when their collections keep every item in order, as `Vec` does. With
+    /// a collection chosen through [`with_collections`](ProcessResults::with_collections) that
+    /// deduplicates or reorders, such as a `BTreeSet`, carry the position inside each success and
+    /// error instead, for example by mapping the results of
+    /// [`async_enumerate`](crate::AsyncIterTools::async_enumerate).
+    pub fn with_indices(mut self) -> Self {
+        self.track_indices = true;
+        self
+    }
+
+    /// Sets the collections that successes and errors are gathered into.
+    ///
+    /// Any collection implementing `Default + Extend` can be used, such as a `HashMap` of
+    /// successes keyed by id, or a `BTreeSet` of deduplicated errors. This is meant to be called
+    /// before the future is polled; results gathered by earlier polls are discarded.
+    ///
+    /// Indices recorded with [`with_indices`](ProcessResults::with_indices) still hold one entry per
+    /// result received, so they no longer line up with a collection that deduplicates or reorders
+    /// its items.
+    pub fn with_collections<S2, C2>(self) -> ProcessResults<I, T, E, S2, C2>
+    where
+        S2: Default,
+        C2: Default,
+    {
+        ProcessResults {
+            iter: self.iter,
+            strategy: self.strategy,
+            track_indices: self.track_indices,
+            success_count: 0,
+            error_count: 0,
+            results: ProcessResultsContainer::default(),
+        }
+    }
 }
 
-impl<I, T, E> Future for ProcessResults<I, T, E>
+impl<I, T, E, S, C> Future for ProcessResults<I, T, E, S, C>
 where
-    I: AsyncIterator<Item = Result<T, E>> + Unpin,
-    T: Unpin,
-    E: Unpin,
+    I: AsyncIterator<Item = Result<T, E>>,
+    S: Default + Extend<T>,
+    C: Default + Extend<E>,
 {
-    type Output = ProcessResultsContainer<T, E>;
+    type Output = ProcessResultsContainer<T, E, S, C>;
 
     /// Polls the future and returns a container of results.
     /// Depending on the strategy, it either:
     /// - `Partition`: Collects all successes and errors.
     /// - `BreakOnError`: Stops at the first error and returns it immediately.
+    /// - `BreakOnErrorKeepSuccesses`: Stops at the first error and returns it together with the
+    ///   successes received before it.
+    /// - `MaxErrors` and `MaxErrorRatio`: Collect successes and errors until the error budget is
+    ///   exceeded.
+    ///
+    /// Results received so far are kept in the future between polls, so it resumes where it left
+    /// off when woken.
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let strategy = self.strategy.clone();
-        let mut pinned_fut = pin!(self.get_mut().iter.sync_iter());
+        let mut this = self.project();
 
         loop {
-            match pinned_fut.as_mut().poll(cx) {
-                Poll::Pending => {}
-                Poll::Ready(res) => {
-                    let mut successes = vec![];
-                    let mut errors = vec![];
-
-                    for item in res {
-                        match item {
-                            Ok(item) => successes.push(item),
-                            Err(error) => {
-                                errors.push(error);
-                                match strategy {
-                                    ProcessResultsStrategy::Partition => {}
-                                    ProcessResultsStrategy::BreakOnError => {
-                                        return Poll::Ready((vec![], errors).into());
-                                    }
-                                }
-                            }
-                        }
+            let next = this.iter.as_mut().poll_next(cx);
+            let position = *this.success_count + *this.error_count;
+            match next {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(Ok(item))) => {
+                    if *this.track_indices {
+                        this.results.success_indices.push(position);
+                    }
+                    this.results.successes.extend(Some(item));
+                    *this.success_count += 1;
+                }
+                Poll::Ready(Some(Err(error))) => {
+                    if *this.track_indices {
+                        this.results.error_indices.push(position);
                     }
+                    this.results.errors.extend(Some(error));
+                    *this.error_count += 1;
+                }
+                Poll::Ready(None) => return Poll::Ready(mem::take(this.results)),
+            }
+
+            let stop_reason = this
+                .strategy
+                .stop_reason(*this.success_count, *this.error_count);
 
-                    return Poll::Ready((successes, errors).into());
+            if let Some(stop_reason) = stop_reason {
+                if let ProcessResultsStrategy::BreakOnError = this.strategy {
+                    this.results.successes = S::default();
+                    this.results.success_indices.clear();
                 }
+                let results = mem::take(this.results);
+                return Poll::Ready(results.with_stop_reason(stop_reason));
             }
         }
     }
 }
 
-impl<I, T, E> Debug for ProcessResults<I, T, E>
+impl<I, T, E, S, C> Debug for ProcessResults<I, T, E, S, C>
 where
     I: AsyncIterator<Item = Result<T, E>> + Debug,
 {
@@ -180,6 +518,7 @@ where
         f.debug_struct("ProcessResults")
             .field("iter", &self.iter)
             .field("strategy", &self.strategy)
+            .field("track_indices", &self.track_indices)
             .finish()
     }
 }
@@ -1,14 +1,26 @@
 use std::{
     fmt::{Debug, Formatter},
+    mem,
     ops::Deref,
-    pin::{Pin, pin},
+    pin::Pin,
     task::{Context, Poll},
     vec::IntoIter,
 };
 
 use crate::iter::AsyncIterator;
 
+/// Drives an async iterator to completion from a `Future::poll` implementation, collecting its
+/// items into a [`SyncIter`].
+///
+/// The items collected so far are kept in the buffer returned by
+/// [`collected_items`](PollSyncIter::collected_items), and the iterator is driven through
+/// [`AsyncIterator::poll_next`], so a poll that returns `Poll::Pending` resumes where the previous
+/// one left off once the task is woken.
 pub trait PollSyncIter: AsyncIterator {
+    /// Returns the buffer holding the items collected by previous calls to
+    /// [`poll_sync_iter`](PollSyncIter::poll_sync_iter).
+    fn collected_items(&mut self) -> &mut Vec<Self::Item>;
+
     #[inline]
     fn poll_sync_iter(
         self: Pin<&mut Self>,
@@ -17,13 +29,16 @@ pub trait PollSyncIter: AsyncIterator {
     where
         Self: Sized + Unpin,
     {
-        // Pin the future so it can be polled.
-        let mut pinned_fut = pin!(self.get_mut().sync_iter());
+        let this = self.get_mut();
 
         loop {
-            match pinned_fut.as_mut().poll(cx) {
-                Poll::Pending => {}
-                Poll::Ready(res) => return Poll::Ready(res),
+            match Pin::new(&mut *this).poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(item)) => this.collected_items().push(item),
+                Poll::Ready(None) => {
+                    let items = mem::take(this.collected_items());
+                    return Poll::Ready(SyncIter::new(items.into_iter()));
+                }
             }
         }
     }
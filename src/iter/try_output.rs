@@ -0,0 +1,80 @@
+use std::ops::ControlFlow;
+
+/// A value that either carries an output to continue with, or short-circuits with a residual.
+///
+/// This is a stable stand-in for the standard `Try` trait, and is what lets
+/// [`try_fold_async`](crate::AsyncIterTools::try_fold_async) stop at the first `Err`, `None` or
+/// `ControlFlow::Break` returned by its closure.
+pub trait TryOutput {
+    /// The value that is passed on when the operation continues.
+    type Output;
+
+    /// The value that is kept when the operation short-circuits.
+    type Residual;
+
+    /// Wraps an output into a value that continues the operation.
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Wraps a residual into a value that short-circuits the operation.
+    fn from_residual(residual: Self::Residual) -> Self;
+
+    /// Splits the value into whether to continue with an output or to break with a residual.
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output>;
+}
+
+impl<T, E> TryOutput for Result<T, E> {
+    type Output = T;
+    type Residual = E;
+
+    fn from_output(output: T) -> Self {
+        Ok(output)
+    }
+
+    fn from_residual(residual: E) -> Self {
+        Err(residual)
+    }
+
+    fn branch(self) -> ControlFlow<E, T> {
+        match self {
+            Ok(output) => ControlFlow::Continue(output),
+            Err(residual) => ControlFlow::Break(residual),
+        }
+    }
+}
+
+impl<T> TryOutput for Option<T> {
+    type Output = T;
+    type Residual = ();
+
+    fn from_output(output: T) -> Self {
+        Some(output)
+    }
+
+    fn from_residual(_residual: ()) -> Self {
+        None
+    }
+
+    fn branch(self) -> ControlFlow<(), T> {
+        match self {
+            Some(output) => ControlFlow::Continue(output),
+            None => ControlFlow::Break(()),
+        }
+    }
+}
+
+impl<B, C> TryOutput for ControlFlow<B, C> {
+    type Output = C;
+    type Residual = B;
+
+    fn from_output(output: C) -> Self {
+        ControlFlow::Continue(output)
+    }
+
+    fn from_residual(residual: B) -> Self {
+        ControlFlow::Break(residual)
+    }
+
+    fn branch(self) -> ControlFlow<B, C> {
+        self
+    }
+}
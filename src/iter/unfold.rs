@@ -0,0 +1,111 @@
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use crate::iter::AsyncIterator;
+
+/// Creates an async iterator from an initial state and an async closure that produces each item
+/// from the state.
+///
+/// The closure is given the current state by value and returns a future. The future resolves to
+/// `Some((state, item))` to yield `item` and continue from `state`, or to `None` to end the
+/// iteration. This is the way to turn `async` logic, such as fetching pages from an API one after
+/// the other, into an [`AsyncIterator`] without writing a `poll_next` state machine by hand.
+/// Each future returned by the closure is boxed, which costs one allocation per item.
+///
+/// ---
+///
+/// # Examples
+///
+/// ```rust
+/// use async_iter_ext::{AsyncIterator, iter::unfold};
+/// use async_std::task;
+///
+/// async fn fetch_page(page: u32) -> Vec<u32> {
+///   // Simulate fetching a page of ids, where the third page is empty
+///   task::yield_now().await;
+///   if page < 2 { vec![page * 2, page * 2 + 1] } else { vec![] }
+/// }
+///
+/// task::block_on(async {
+///   let pages = unfold(0, async |page| {
+///     let ids = fetch_page(page).await;
+///     (!ids.is_empty()).then_some((page + 1, ids))
+///   });
+///
+///   assert_eq!(pages.async_collect::<Vec<_>>().await, vec![vec![0, 1], vec![2, 3]]);
+/// });
+/// ```
+pub fn unfold<St, T, F, Fut>(initial_state: St, f: F) -> Unfold<St, F, Fut>
+where
+    F: FnMut(St) -> Fut,
+    Fut: Future<Output = Option<(St, T)>>,
+{
+    Unfold {
+        f,
+        state: Some(initial_state),
+        pending: None,
+    }
+}
+
+/// An async iterator that produces its items by running an async closure on a state.
+///
+/// This struct is created by the [`unfold`] function.
+#[must_use = "async iterators are lazy and do nothing unless consumed"]
+pub struct Unfold<St, F, Fut> {
+    f: F,
+    state: Option<St>,
+    pending: Option<Pin<Box<Fut>>>,
+}
+
+impl<St, F, Fut> Unpin for Unfold<St, F, Fut> {}
+
+impl<St, T, F, Fut> AsyncIterator for Unfold<St, F, Fut>
+where
+    F: FnMut(St) -> Fut,
+    Fut: Future<Output = Option<(St, T)>>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if let Some(state) = this.state.take() {
+            this.pending = Some(Box::pin((this.f)(state)));
+        }
+
+        let Some(pending) = this.pending.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        let output = ready!(pending.as_mut().poll(cx));
+        this.pending = None;
+        Poll::Ready(output.map(|(state, item)| {
+            this.state = Some(state);
+            item
+        }))
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        if self.state.is_none() && self.pending.is_none() {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+/// Provides a `Debug` implementation for `Unfold` that shows the current state. The state is
+/// `None` while the closure's future is running or after the iteration has ended.
+impl<St, F, Fut> Debug for Unfold<St, F, Fut>
+where
+    St: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Unfold")
+            .field("state", &self.state)
+            .finish()
+    }
+}
@@ -1,17 +1,29 @@
 #![doc = include_str!("../README.md")]
 
-use combinator::{filter::AsyncFilter, map::AsyncMap};
+use std::{ops::ControlFlow, pin::pin};
+
+use combinator::{
+    chain::AsyncChain, enumerate::AsyncEnumerate, filter::AsyncFilter, filter_map::AsyncFilterMap,
+    filter_ref::AsyncFilterRef, flat_map::AsyncFlatMap, flat_map_iter::AsyncFlatMapIter,
+    flatten::AsyncFlatten, flatten_iter::AsyncFlattenIter, fuse::AsyncFuse, inspect::AsyncInspect,
+    map::AsyncMap, map_buffered::AsyncMapBuffered, map_mut::AsyncMapMut,
+    map_unordered::AsyncMapUnordered, map_while::AsyncMapWhile, scan::AsyncScan, skip::AsyncSkip,
+    skip_while::AsyncSkipWhile, step_by::AsyncStepBy, take::AsyncTake, take_while::AsyncTakeWhile,
+    zip::AsyncZip,
+};
 
 pub mod combinator;
 pub mod iter;
 mod option;
 mod result;
+#[cfg(feature = "futures")]
+pub mod stream;
 
 pub use iter::AsyncIterator;
 pub use option::AsyncOptionTools;
 pub use result::AsyncResultTools;
 
-use crate::iter::process_result::ProcessResults;
+use crate::iter::{TryOutput, next_pinned, process_result::ProcessResults};
 
 /// Extension methods for asynchronous iterators.
 ///
@@ -31,13 +43,12 @@ pub trait AsyncIterTools: AsyncIterator {
     /// ```rust
     /// use std::time::Duration;
     ///
-    /// use async_iter_ext::AsyncIterTools;
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
     /// use async_std::task;
     ///
     /// task::block_on(async {
     ///   let items = [1, 2, 3, 4];
-    ///   items
-    ///     .iter()
+    ///   iter(&items)
     ///     .for_each_async(|item| {
     ///       async move {
     ///         // Simulate some async work
@@ -48,25 +59,130 @@ pub trait AsyncIterTools: AsyncIterator {
     ///     .await;
     /// });
     /// ```
-    fn for_each_async<F, Fut>(mut self, f: F) -> impl Future<Output = ()>
+    fn for_each_async<F, Fut>(self, mut f: F) -> impl Future<Output = ()>
     where
         Self: Sized,
-        F: Fn(Self::Item) -> Fut,
+        F: FnMut(Self::Item) -> Fut,
         Fut: Future<Output = ()>,
     {
         async move {
-            while let Some(item) = self.next_async().await {
+            let mut this = pin!(self);
+            while let Some(item) = next_pinned(this.as_mut()).await {
+                f(item).await;
+            }
+        }
+    }
+
+    /// Calls an `AsyncFnMut` closure on each element of an iterator.
+    ///
+    /// This works like [`for_each_async`](AsyncIterTools::for_each_async), but accepts native
+    /// async closures whose future borrows the closure's captured state, such as a set that is
+    /// updated across the `.await` points of the closure body.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let mut seen = HashSet::new();
+    ///   iter([1, 2, 2, 3, 1])
+    ///     .for_each_async_mut(async |item| {
+    ///       task::yield_now().await;
+    ///       seen.insert(item);
+    ///     })
+    ///     .await;
+    ///
+    ///   assert_eq!(seen.len(), 3);
+    /// });
+    /// ```
+    fn for_each_async_mut<F>(self, mut f: F) -> impl Future<Output = ()>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item),
+    {
+        async move {
+            let mut this = pin!(self);
+            while let Some(item) = next_pinned(this.as_mut()).await {
                 f(item).await;
             }
         }
     }
 
+    /// Calls an async closure on each element of an iterator, running up to `limit` of the
+    /// closures concurrently.
+    ///
+    /// The returned future completes once the iterator is exhausted and every closure that was
+    /// started has finished. The closures are driven by the future itself, so no particular
+    /// runtime or spawning is required.
+    ///
+    /// ---
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{
+    ///   sync::atomic::{AtomicUsize, Ordering},
+    ///   time::Duration,
+    /// };
+    ///
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let sent = AtomicUsize::new(0);
+    ///   iter(0..100)
+    ///     .for_each_concurrent(10, |_| {
+    ///       let sent = &sent;
+    ///       async move {
+    ///         // Simulate sending a notification
+    ///         task::sleep(Duration::from_millis(10)).await;
+    ///         sent.fetch_add(1, Ordering::SeqCst);
+    ///       }
+    ///     })
+    ///     .await;
+    ///
+    ///   assert_eq!(sent.load(Ordering::SeqCst), 100);
+    /// });
+    /// ```
+    fn for_each_concurrent<F, Fut>(self, limit: usize, f: F) -> impl Future<Output = ()>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        let in_flight = AsyncMapUnordered::new(self, limit, f);
+        async move {
+            let mut in_flight = pin!(in_flight);
+            while next_pinned(in_flight.as_mut()).await.is_some() {}
+        }
+    }
+
     /// Applies an async closure to each item of the iterator, returning a new iterator
     /// of the results of each async computation.
     ///
     /// This is similar to the standard `Iterator::map` method, but works with asynchronous
     /// closures that return `Future`s.
     ///
+    /// The closure may mutate its captured state, but the returned future is kept inside the
+    /// adapter between polls, so it cannot borrow from the closure itself. Move what the future
+    /// needs into an `async move` block, or use [`map_async_mut`](AsyncIterTools::map_async_mut)
+    /// when the work has to borrow captured state.
+    ///
+    /// Each future returned by the closure is boxed, so the adapter stays `Unpin` whatever the
+    /// future type is, at the cost of one allocation per item.
+    ///
     /// ---
     ///
     /// # Examples
@@ -74,13 +190,12 @@ pub trait AsyncIterTools: AsyncIterator {
     /// ```rust
     /// use std::time::Duration;
     ///
-    /// use async_iter_ext::{AsyncIterTools, AsyncIterator};
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
     /// use async_std::task;
     ///
     /// let multiplied_by_two = task::block_on(async {
     ///   let items = [1, 2, 3, 4];
-    ///   items
-    ///     .iter()
+    ///   iter(&items)
     ///     .map_async(|item| {
     ///       async move {
     ///         // Simulate async transformation
@@ -92,36 +207,183 @@ pub trait AsyncIterTools: AsyncIterator {
     ///     .await
     /// });
     /// ```
-    fn map_async<B, F, Fut>(self, f: F) -> AsyncMap<Self, F>
+    fn map_async<B, F, Fut>(self, f: F) -> AsyncMap<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        AsyncMap::new(self, f)
+    }
+
+    /// Applies an `AsyncFnMut` closure to each item of the iterator, returning a new iterator of
+    /// the results.
+    ///
+    /// This works like [`map_async`](AsyncIterTools::map_async), but accepts native async
+    /// closures whose future borrows the closure's captured state, such as a closure that records
+    /// every item it sees. The closure and each call future are boxed together, which costs one
+    /// allocation per item. The returned adapter is `Send` whenever the closure and its futures
+    /// are, but its type cannot be named, so store it behind `impl AsyncIterator`. Inside a task
+    /// that has to be `Send`, let the closure own its state with `async move`: the compiler cannot
+    /// yet prove `Send` for a closure borrowing the task's own locals.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let mut seen = Vec::new();
+    ///   let doubled = iter([1, 2, 3])
+    ///     .map_async_mut(async |item| {
+    ///       seen.push(item);
+    ///       item * 2
+    ///     })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(doubled, vec![2, 4, 6]);
+    ///   assert_eq!(seen, vec![1, 2, 3]);
+    /// });
+    /// ```
+    fn map_async_mut<B, F>(self, f: F) -> AsyncMapMut<Self, F, impl Future<Output = (F, B)>>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item) -> B,
+    {
+        AsyncMapMut::new(self, f, combinator::map_mut::call_mut::<F, Self::Item, B>)
+    }
+
+    /// Applies an async closure to each item of the iterator, running up to `limit` of the
+    /// resulting futures concurrently while yielding their results in input order.
+    ///
+    /// This works like [`map_async`](AsyncIterTools::map_async), but keeps pulling items from the
+    /// underlying iterator while earlier futures are still running. It is useful for fanning out
+    /// independent I/O, such as one request per id, without losing the ordering of the input.
+    ///
+    /// Each mapped future is boxed, costing one allocation per item, and every wake-up polls all
+    /// of the futures in flight, so the cost of a poll grows with `limit` but not with the number
+    /// of items.
+    ///
+    /// ---
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// let multiplied_by_two = task::block_on(async {
+    ///   let items = [4, 3, 2, 1];
+    ///   iter(&items)
+    ///     .map_async_buffered(2, |item| {
+    ///       async move {
+    ///         // Earlier items take longer, but the output order is kept
+    ///         task::sleep(Duration::from_millis(item * 10)).await;
+    ///         item * 2
+    ///       }
+    ///     })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await
+    /// });
+    ///
+    /// assert_eq!(multiplied_by_two, vec![8, 6, 4, 2]);
+    /// ```
+    fn map_async_buffered<B, F, Fut>(self, limit: usize, f: F) -> AsyncMapBuffered<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        AsyncMapBuffered::new(self, limit, f)
+    }
+
+    /// Applies an async closure to each item of the iterator, running up to `limit` of the
+    /// resulting futures concurrently and yielding their results as soon as they complete.
+    ///
+    /// This works like [`map_async_buffered`](AsyncIterTools::map_async_buffered), but does not
+    /// preserve the order of the input, so one slow future never holds back results that are
+    /// already available. If the source of each result matters, return it from the closure. As
+    /// with `map_async_buffered`, each future is boxed and every wake-up polls all of the futures
+    /// in flight.
+    ///
+    /// ---
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// let completed = task::block_on(async {
+    ///   let items = [3, 1, 2];
+    ///   iter(items)
+    ///     .map_async_unordered(3, |item| {
+    ///       async move {
+    ///         // Shorter sleeps finish first
+    ///         task::sleep(Duration::from_millis(item * 50)).await;
+    ///         (item, item * 2)
+    ///       }
+    ///     })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await
+    /// });
+    ///
+    /// assert_eq!(completed, vec![(1, 2), (2, 4), (3, 6)]);
+    /// ```
+    fn map_async_unordered<B, F, Fut>(self, limit: usize, f: F) -> AsyncMapUnordered<Self, F, Fut>
     where
         Self: Sized,
-        F: Fn(Self::Item) -> Fut,
+        F: FnMut(Self::Item) -> Fut,
         Fut: Future<Output = B>,
     {
-        AsyncMap { iter: self, f }
+        AsyncMapUnordered::new(self, limit, f)
     }
 
     /// Filters the items of an iterator using an asynchronous predicate.
     ///
     /// This works like the standard `Iterator::filter`, but allows the predicate
-    /// to be asynchronous by returning a `Future<Output = bool>`.
+    /// to be asynchronous by returning a `Future<Output = bool>`. The returned future cannot
+    /// borrow from the predicate itself; use [`filter_async_ref`](AsyncIterTools::filter_async_ref)
+    /// for a native async closure that updates its captured state. Like
+    /// [`map_async`](AsyncIterTools::map_async), each predicate future is boxed, which costs one
+    /// allocation per item.
     ///
     /// ---
     ///
     /// > ⚠️ Warning: The item type must implement `Clone` because filtering requires that the item is cloned when the predicate is run.
+    /// > Use [`filter_async_ref`](AsyncIterTools::filter_async_ref) to avoid the clone.
     ///
     /// ---
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use async_iter_ext::{AsyncIterTools, AsyncIterator};
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
     /// use async_std::task;
     ///
     /// task::block_on(async {
     ///   let items = [1, 2, 3, 4, 5, 6];
-    ///   let filtered = items
-    ///     .iter()
+    ///   let filtered = iter(&items)
     ///     .filter_async(|item| {
     ///       async move {
     ///         // Keep even numbers only
@@ -131,76 +393,1253 @@ pub trait AsyncIterTools: AsyncIterator {
     ///     .async_collect::<Vec<_>>();
     /// });
     /// ```
-    fn filter_async<F, Fut>(self, f: F) -> AsyncFilter<Self, F>
+    fn filter_async<F, Fut>(self, f: F) -> AsyncFilter<Self, F, Fut>
     where
         Self: Sized,
-        F: Fn(Self::Item) -> Fut,
+        F: FnMut(Self::Item) -> Fut,
         Fut: Future<Output = bool>,
         Self::Item: Clone,
     {
-        AsyncFilter { iter: self, f }
+        AsyncFilter::new(self, f)
     }
 
-    /// Consumes the async iterator and returns a `ProcessResults` future that collects
-    /// successes and errors based on a specified strategy.
+    /// Filters the items of an iterator using an `AsyncFnMut` predicate that receives a reference
+    /// to each item.
     ///
-    /// This is a convenience method for turning an `AsyncIterator<Item = Result<T, E>>`
-    /// into a `ProcessResults`, which is a future that resolves to a
-    /// `ProcessResultsContainer<T, E>`.
+    /// This works like [`filter_async`](AsyncIterTools::filter_async), but does not require the
+    /// item type to implement `Clone`, which makes it suitable for large or non-cloneable items
+    /// such as file handles. The predicate's future may borrow both the item and the predicate's
+    /// captured state across `.await` points. The predicate, the item and each call future are
+    /// boxed together, which costs one allocation per item. As with
+    /// [`map_async_mut`](AsyncIterTools::map_async_mut), the returned adapter is `Send` whenever
+    /// everything it holds is, but its type cannot be named, and a predicate used inside a `Send`
+    /// task should own its state.
     ///
-    /// By default, the `ProcessResultsStrategy::Partition` strategy is used,
-    /// which means all items will be processed and separated into successes and errors.
-    /// You can change the strategy using `.with_process_strategy(...)`.
+    /// A closure returning an `async move` block is accepted as well, but needs its argument type
+    /// annotated, as in `|item: &Item| async move { .. }`.
     ///
-    /// # Type Parameters
-    /// - `T`: The success type inside the `Result`.
-    /// - `E`: The error type inside the `Result`.
+    /// ---
     ///
-    /// # Returns
-    /// A `ProcessResults<Self, T, E>` future that resolves to `ProcessResultsContainer<T, E>`.
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// struct Upload {
+    ///   name: String,
+    ///   bytes: Vec<u8>,
+    /// }
+    ///
+    /// let (names, checked) = task::block_on(async {
+    ///   let uploads = vec![
+    ///     Upload { name: "empty.txt".into(), bytes: vec![] },
+    ///     Upload { name: "notes.txt".into(), bytes: vec![1, 2, 3] },
+    ///   ];
+    ///
+    ///   let mut checked = 0;
+    ///   let names = iter(uploads)
+    ///     .filter_async_ref(async |upload| {
+    ///       // Simulate an async quota check
+    ///       task::sleep(Duration::from_millis(10)).await;
+    ///       checked += 1;
+    ///       !upload.bytes.is_empty()
+    ///     })
+    ///     .map_async(|upload| async move { upload.name })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   (names, checked)
+    /// });
+    ///
+    /// assert_eq!(names, vec!["notes.txt"]);
+    /// assert_eq!(checked, 2);
+    /// ```
+    fn filter_async_ref<F>(
+        self,
+        f: F,
+    ) -> AsyncFilterRef<Self, F, impl Future<Output = (F, Self::Item, bool)>>
+    where
+        Self: Sized,
+        F: AsyncFnMut(&Self::Item) -> bool,
+    {
+        AsyncFilterRef::new(self, f, combinator::filter_ref::call_mut::<F, Self::Item>)
+    }
+
+    /// Applies an async closure to each item of the iterator and yields only the values it
+    /// resolves to `Some`.
+    ///
+    /// This is similar to the standard `Iterator::filter_map` method, and replaces chaining a
+    /// [`map_async`](AsyncIterTools::map_async) that returns `Option<B>` with a filter removing
+    /// the `None`s. As with `map_async`, each future is boxed, costing one allocation per item.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// let numbers = task::block_on(async {
+    ///   let inputs = ["1", "two", "3"];
+    ///   iter(inputs)
+    ///     .filter_map_async(|input| {
+    ///       async move {
+    ///         // Simulate an async lookup
+    ///         task::sleep(Duration::from_millis(10)).await;
+    ///         input.parse::<u32>().ok()
+    ///       }
+    ///     })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await
+    /// });
+    ///
+    /// assert_eq!(numbers, vec![1, 3]);
+    /// ```
+    fn filter_map_async<B, F, Fut>(self, f: F) -> AsyncFilterMap<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = Option<B>>,
+    {
+        AsyncFilterMap::new(self, f)
+    }
+
+    /// Applies an async closure to each item of the iterator that resolves to an inner async
+    /// iterator, and yields the items of each inner iterator in turn.
+    ///
+    /// This is similar to the standard `Iterator::flat_map`, but the closure returns a future, so
+    /// producing the inner items, such as fetching a page of records, can be asynchronous. The
+    /// next item is only pulled from this iterator once the current inner iterator is exhausted.
+    /// Each future is boxed while it runs, which costs one allocation per outer item.
+    ///
+    /// The future resolves to an [`AsyncIterator`]. When it resolves to a collection such as a
+    /// `Vec`, use [`flat_map_iter_async`](AsyncIterTools::flat_map_iter_async) instead.
+    ///
+    /// ---
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::process_result::ProcessResultsStrategy};
+    /// use std::time::Duration;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
     /// use async_std::task;
     ///
+    /// async fn fetch_page(tenant: &str, page: u32) -> String {
+    ///   // Simulate fetching one page of records for the tenant
+    ///   task::sleep(Duration::from_millis(10)).await;
+    ///   format!("{tenant}-{page}")
+    /// }
+    ///
     /// task::block_on(async {
-    ///   // Partition strategy: collect all successes and errors
-    ///   let results = vec![Ok(1), Err("err1"), Ok(2), Err("err2")]
-    ///     .into_iter()
-    ///     .process_results::<i32, &str>()
+    ///   let records = iter(["acme", "globex"])
+    ///     .flat_map_async(|tenant| async move {
+    ///       iter(1..=2).map_async(move |page| fetch_page(tenant, page))
+    ///     })
+    ///     .async_collect::<Vec<_>>()
     ///     .await;
-    ///   assert_eq!(results.successes(), &vec![1, 2]);
-    ///   assert_eq!(results.errors(), &vec!["err1", "err2"]);
     ///
-    ///   // Convert to result and unwrap error. OBS: Only gets the first error
-    ///   let first_result_err = results.into_result().unwrap_err();
-    ///   assert_eq!(first_result_err, "err1");
+    ///   assert_eq!(records, vec!["acme-1", "acme-2", "globex-1", "globex-2"]);
+    /// });
+    /// ```
+    fn flat_map_async<U, F, Fut>(self, f: F) -> AsyncFlatMap<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = U>,
+        U: AsyncIterator,
+    {
+        AsyncFlatMap::new(self, f)
+    }
+
+    /// Applies an async closure to each item of the iterator that resolves to a collection or
+    /// other `IntoIterator`, and yields the items of each in turn.
     ///
-    ///   // Partition strategy: collect all successes
-    ///   let results = vec![Ok(1), Ok(2)].into_iter().process_results::<i32, &str>().await;
-    ///   assert_eq!(results.successes(), &vec![1, 2]);
+    /// This works like [`flat_map_async`](AsyncIterTools::flat_map_async), but the iterator
+    /// obtained from `into_iter()` on the output is driven synchronously, which suits closures that
+    /// fetch a whole page of items at once. The next item is only pulled from this iterator once
+    /// the current inner iterator is exhausted. Each future is boxed while it runs, which costs one
+    /// allocation per outer item.
     ///
-    ///   // Convert to result and unwrap
-    ///   let successes = results.into_result().unwrap();
-    ///   assert_eq!(successes, vec![1, 2]);
+    /// ---
     ///
-    ///   // BreakOnError strategy: stop at the first error
-    ///   let results = vec![Ok(1), Err("early"), Ok(2)]
-    ///     .into_iter()
-    ///     .process_results::<i32, &str>()
-    ///     .with_process_strategy(ProcessResultsStrategy::BreakOnError)
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// async fn fetch_records(tenant: &str) -> Vec<String> {
+    ///   // Simulate fetching a page of records for the tenant
+    ///   task::sleep(Duration::from_millis(10)).await;
+    ///   vec![format!("{tenant}-1"), format!("{tenant}-2")]
+    /// }
+    ///
+    /// task::block_on(async {
+    ///   let records = iter(["acme", "globex"])
+    ///     .flat_map_iter_async(fetch_records)
+    ///     .async_collect::<Vec<_>>()
     ///     .await;
-    ///   assert_eq!(results.successes(), &vec![]); // did not continue after first error
-    ///   assert_eq!(results.errors(), &vec!["early"]);
+    ///
+    ///   assert_eq!(records, vec!["acme-1", "acme-2", "globex-1", "globex-2"]);
     /// });
     /// ```
-    fn process_results<T, E>(self) -> ProcessResults<Self, T, E>
+    fn flat_map_iter_async<U, F, Fut>(self, f: F) -> AsyncFlatMapIter<Self, F, Fut>
     where
-        Self: Sized + AsyncIterator<Item = Result<T, E>>,
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = U>,
+        U: IntoIterator,
     {
-        ProcessResults::new(self)
+        AsyncFlatMapIter::new(self, f)
+    }
+
+    /// Creates an async iterator that yields the items of every inner async iterator yielded by
+    /// this one in turn.
+    ///
+    /// This is similar to the standard `Iterator::flatten`, for inner items that are
+    /// [`AsyncIterator`]s. The next inner iterator is only pulled once the current one is
+    /// exhausted. Use [`async_flatten_iter`](AsyncIterTools::async_flatten_iter) when the inner
+    /// items are collections or other `IntoIterator`s.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let flattened = iter([1, 2, 3])
+    ///     .map_async(|count| async move { iter(0..count) })
+    ///     .async_flatten()
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(flattened, vec![0, 0, 1, 0, 1, 2]);
+    /// });
+    /// ```
+    fn async_flatten(self) -> AsyncFlatten<Self>
+    where
+        Self: Sized,
+        Self::Item: AsyncIterator,
+    {
+        AsyncFlatten::new(self)
+    }
+
+    /// Creates an async iterator that yields the items of every collection or other
+    /// `IntoIterator` yielded by this one in turn.
+    ///
+    /// This works like [`async_flatten`](AsyncIterTools::async_flatten), but calls `into_iter()` on
+    /// each inner item and drives the resulting iterator synchronously. The next inner item is
+    /// only pulled once the current one is exhausted.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let flattened = iter([vec![1, 2], vec![], vec![3]])
+    ///     .map_async(|page| async move { page })
+    ///     .async_flatten_iter()
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(flattened, vec![1, 2, 3]);
+    /// });
+    /// ```
+    fn async_flatten_iter(self) -> AsyncFlattenIter<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator,
+    {
+        AsyncFlattenIter::new(self)
+    }
+
+    /// Creates an async iterator that yields every item of this iterator, followed by every item
+    /// of `other`.
+    ///
+    /// This is similar to the standard `Iterator::chain`. `other` can be any [`AsyncIterator`]
+    /// with the same item type, and is only polled once this iterator is exhausted.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let cached = iter([1, 2]);
+    ///   let fetched = iter([3, 4]).map_async(|item| async move { item });
+    ///
+    ///   let all = cached.async_chain(fetched).async_collect::<Vec<_>>().await;
+    ///   assert_eq!(all, vec![1, 2, 3, 4]);
+    /// });
+    /// ```
+    fn async_chain<U>(self, other: U) -> AsyncChain<Self, U>
+    where
+        Self: Sized,
+        U: AsyncIterator<Item = Self::Item>,
+    {
+        AsyncChain::new(self, other)
+    }
+
+    /// Creates an async iterator that pairs up the items of this iterator and `other`, stopping as
+    /// soon as either of them is exhausted.
+    ///
+    /// This is similar to the standard `Iterator::zip`, except that both iterators are polled at
+    /// the same time. Pulling the next pair therefore takes as long as the slower side, not as
+    /// long as both sides combined.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let names = iter(["ada", "grace", "linus"]);
+    ///   let scores = iter(1..).map_async(|id| async move {
+    ///     // Simulate looking up a score
+    ///     task::sleep(Duration::from_millis(10)).await;
+    ///     id * 10
+    ///   });
+    ///
+    ///   let paired = names.async_zip(scores).async_collect::<Vec<_>>().await;
+    ///   assert_eq!(paired, vec![("ada", 10), ("grace", 20), ("linus", 30)]);
+    /// });
+    /// ```
+    fn async_zip<U>(self, other: U) -> AsyncZip<Self, U>
+    where
+        Self: Sized,
+        U: AsyncIterator,
+    {
+        AsyncZip::new(self, other)
+    }
+
+    /// Creates an async iterator that yields each item together with its index, starting from
+    /// zero.
+    ///
+    /// This is similar to the standard `Iterator::enumerate`, and yields `(index, item)` pairs.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let indexed = iter(["a", "b", "c"])
+    ///     .filter_async(|item| async move { item != "b" })
+    ///     .async_enumerate()
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(indexed, vec![(0, "a"), (1, "c")]);
+    /// });
+    /// ```
+    fn async_enumerate(self) -> AsyncEnumerate<Self>
+    where
+        Self: Sized,
+    {
+        AsyncEnumerate::new(self)
+    }
+
+    /// Creates an async iterator that runs an async side effect for each item, given a reference
+    /// to it, before yielding the item unchanged.
+    ///
+    /// This is similar to the standard `Iterator::inspect`, and is useful for logging or
+    /// recording metrics along an async pipeline. The side effect of each item is awaited before
+    /// the item is yielded, and its future cannot borrow the item, so copy what it logs out of the
+    /// reference first. Each side effect future is boxed, costing one allocation per item.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let items = iter([1, 2, 3])
+    ///     .inspect_async(|item| {
+    ///       let item = *item;
+    ///       async move {
+    ///         // Simulate recording a metric
+    ///         task::yield_now().await;
+    ///         println!("processing {item}");
+    ///       }
+    ///     })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(items, vec![1, 2, 3]);
+    /// });
+    /// ```
+    fn inspect_async<F, Fut>(self, f: F) -> AsyncInspect<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        AsyncInspect::new(self, f)
+    }
+
+    /// Creates an async iterator that returns `None` forever once this iterator has returned
+    /// `None` for the first time.
+    ///
+    /// This is similar to the standard `Iterator::fuse`. Calling `next_async` past the end of an
+    /// async iterator is not guaranteed to keep returning `None`, and may even start work again.
+    /// The fused iterator drops this iterator as soon as it is exhausted, so it is never polled
+    /// past its end.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::task::Poll;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::poll_fn};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   // Alternates between yielding an item and returning `None`
+    ///   let mut count = 0;
+    ///   let flaky = poll_fn(move |_cx| {
+    ///     count += 1;
+    ///     Poll::Ready((count % 2 == 1).then_some(count))
+    ///   });
+    ///
+    ///   let mut fused = flaky.async_fuse();
+    ///   assert_eq!(fused.next_async().await, Some(1));
+    ///   assert_eq!(fused.next_async().await, None);
+    ///   assert_eq!(fused.next_async().await, None);
+    /// });
+    /// ```
+    fn async_fuse(self) -> AsyncFuse<Self>
+    where
+        Self: Sized,
+    {
+        AsyncFuse::new(self)
+    }
+
+    /// Creates an async iterator that yields at most the first `n` items of this one.
+    ///
+    /// This is similar to the standard `Iterator::take`. Once `n` items have been yielded, the
+    /// underlying iterator is not polled again, so async work behind it, such as a
+    /// [`map_async`](AsyncIterTools::map_async) step, only runs for the items that are yielded.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let first_two = iter(1..)
+    ///     .map_async(|item| async move { item * 10 })
+    ///     .async_take(2)
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(first_two, vec![10, 20]);
+    /// });
+    /// ```
+    fn async_take(self, n: usize) -> AsyncTake<Self>
+    where
+        Self: Sized,
+    {
+        AsyncTake::new(self, n)
+    }
+
+    /// Creates an async iterator that skips the first `n` items of this one.
+    ///
+    /// This is similar to the standard `Iterator::skip`. The skipped items are pulled from the
+    /// underlying iterator, and dropped, the first time an item is requested.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let rest = iter([1, 2, 3, 4])
+    ///     .map_async(|item| async move { item * 10 })
+    ///     .async_skip(2)
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(rest, vec![30, 40]);
+    /// });
+    /// ```
+    fn async_skip(self, n: usize) -> AsyncSkip<Self>
+    where
+        Self: Sized,
+    {
+        AsyncSkip::new(self, n)
+    }
+
+    /// Creates an async iterator that yields the first item of this one and then every `step`th
+    /// item after it.
+    ///
+    /// This is similar to the standard `Iterator::step_by`. The items in between are pulled from
+    /// the underlying iterator and dropped.
+    ///
+    /// ---
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let every_third = iter(0..10)
+    ///     .map_async(|item| async move { item })
+    ///     .async_step_by(3)
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(every_third, vec![0, 3, 6, 9]);
+    /// });
+    /// ```
+    fn async_step_by(self, step: usize) -> AsyncStepBy<Self>
+    where
+        Self: Sized,
+    {
+        AsyncStepBy::new(self, step)
+    }
+
+    /// Creates an async iterator that yields items while an async predicate, given a reference to
+    /// each item, resolves to `true`.
+    ///
+    /// This is similar to the standard `Iterator::take_while`. The predicate is awaited exactly
+    /// once per item. The first item it rejects is dropped, and the iterator is fused from then
+    /// on: it keeps returning `None` without polling the underlying iterator again. The
+    /// predicate's future cannot borrow the item, so read what it needs from the reference before
+    /// the `async move` block, as with the timestamp below. Each predicate future is boxed, which
+    /// costs one allocation per item.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// async fn last_sync_watermark() -> u64 {
+    ///   // Simulate reading the watermark from a store
+    ///   task::yield_now().await;
+    ///   100
+    /// }
+    ///
+    /// task::block_on(async {
+    ///   let records = [(130, "c"), (120, "b"), (90, "a"), (140, "late")];
+    ///   let new_records = iter(records)
+    ///     .take_while_async(|(timestamp, _)| {
+    ///       let timestamp = *timestamp;
+    ///       async move { timestamp > last_sync_watermark().await }
+    ///     })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(new_records, vec![(130, "c"), (120, "b")]);
+    /// });
+    /// ```
+    fn take_while_async<F, Fut>(self, f: F) -> AsyncTakeWhile<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        AsyncTakeWhile::new(self, f)
+    }
+
+    /// Creates an async iterator that skips items while an async predicate, given a reference to
+    /// each item, resolves to `true`, and yields every item from the first rejected one onwards.
+    ///
+    /// This is similar to the standard `Iterator::skip_while`. The predicate is awaited exactly
+    /// once per item until it first resolves to `false`, and is not called again after that. Like
+    /// [`take_while_async`](AsyncIterTools::take_while_async), its future cannot borrow the item,
+    /// and is boxed, costing one allocation per item it is called for.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let items = iter([1, 2, 5, 1, 6])
+    ///     .skip_while_async(|item| {
+    ///       let item = *item;
+    ///       async move { item < 3 }
+    ///     })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(items, vec![5, 1, 6]);
+    /// });
+    /// ```
+    fn skip_while_async<F, Fut>(self, f: F) -> AsyncSkipWhile<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        AsyncSkipWhile::new(self, f)
+    }
+
+    /// Creates an async iterator that maps items with an async closure and yields the `Some`
+    /// values it resolves to, stopping at the first `None`.
+    ///
+    /// This is similar to the standard `Iterator::map_while`. The closure is awaited exactly once
+    /// per item, and the iterator is fused after the first `None`. Each future is boxed, which
+    /// costs one allocation per item.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let numbers = iter(["1", "2", "three", "4"])
+    ///     .map_while_async(|input| async move { input.parse::<u32>().ok() })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(numbers, vec![1, 2]);
+    /// });
+    /// ```
+    fn map_while_async<B, F, Fut>(self, f: F) -> AsyncMapWhile<Self, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Fut,
+        Fut: Future<Output = Option<B>>,
+    {
+        AsyncMapWhile::new(self, f)
+    }
+
+    /// Creates an async iterator that threads a state through an async closure, yielding a value
+    /// per item until the closure resolves to `None`.
+    ///
+    /// This is similar to the standard `Iterator::scan`, except that the state is passed to the
+    /// closure by value and handed back with the yielded value as `Some((state, value))`. This
+    /// lets the returned future own the state across `.await` points and update it from the
+    /// result of async work. Resolving to `None` ends the iteration, and the underlying iterator
+    /// is not polled again. Each future is boxed, costing one allocation per item.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// async fn fetch_reading(sensor: u32) -> i32 {
+    ///   // Simulate reading a sensor
+    ///   task::yield_now().await;
+    ///   [10, 13, 11, 20][sensor as usize]
+    /// }
+    ///
+    /// task::block_on(async {
+    ///   // Running deltas between consecutive readings
+    ///   let deltas = iter(0..4)
+    ///     .scan_async(None, |previous: Option<i32>, sensor| async move {
+    ///       let reading = fetch_reading(sensor).await;
+    ///       let delta = previous.map_or(0, |previous| reading - previous);
+    ///       Some((Some(reading), delta))
+    ///     })
+    ///     .async_collect::<Vec<_>>()
+    ///     .await;
+    ///
+    ///   assert_eq!(deltas, vec![0, 3, -2, 9]);
+    /// });
+    /// ```
+    fn scan_async<St, B, F, Fut>(self, initial_state: St, f: F) -> AsyncScan<Self, St, F, Fut>
+    where
+        Self: Sized,
+        F: FnMut(St, Self::Item) -> Fut,
+        Fut: Future<Output = Option<(St, B)>>,
+    {
+        AsyncScan::new(self, initial_state, f)
+    }
+
+    /// Folds every item into an accumulator by applying an async closure, returning the final
+    /// accumulator.
+    ///
+    /// This is similar to the standard `Iterator::fold`. Items are pulled and folded one at a time,
+    /// so nothing is collected into memory up front.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// let total = task::block_on(async {
+    ///   let pages = [vec![3, 4], vec![5]];
+    ///   iter(pages)
+    ///     .fold_async(0, async |total, page| {
+    ///       // Simulate summing a page fetched from an API
+    ///       task::sleep(Duration::from_millis(10)).await;
+    ///       total + page.iter().sum::<i32>()
+    ///     })
+    ///     .await
+    /// });
+    ///
+    /// assert_eq!(total, 12);
+    /// ```
+    fn fold_async<B, F>(self, init: B, mut f: F) -> impl Future<Output = B>
+    where
+        Self: Sized,
+        F: AsyncFnMut(B, Self::Item) -> B,
+    {
+        async move {
+            let mut this = pin!(self);
+            let mut accumulator = init;
+            while let Some(item) = next_pinned(this.as_mut()).await {
+                accumulator = f(accumulator, item).await;
+            }
+            accumulator
+        }
+    }
+
+    /// Folds items into an accumulator with a fallible async closure, stopping at the first
+    /// failure.
+    ///
+    /// This is similar to the standard `Iterator::try_fold`. The closure returns a [`TryOutput`]
+    /// such as `Result`, `Option` or `ControlFlow`. The fold stops as soon as it returns `Err`,
+    /// `None` or `Break`, and that value is returned. Otherwise the final accumulator is returned
+    /// wrapped in `Ok`, `Some` or `Continue`. The iterator is taken by reference, so iteration can
+    /// resume after the item that caused the short-circuit.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let mut items = iter([1u8, 100, 200, 3]);
+    ///   let sum = items
+    ///     .try_fold_async(0u8, async |total, item| total.checked_add(item))
+    ///     .await;
+    ///
+    ///   // 1 + 100 + 200 overflows, so the fold stops after the third item
+    ///   assert_eq!(sum, None);
+    ///   assert_eq!(items.next_async().await, Some(3));
+    /// });
+    /// ```
+    fn try_fold_async<B, F, R>(&mut self, init: B, mut f: F) -> impl Future<Output = R>
+    where
+        Self: Unpin,
+        F: AsyncFnMut(B, Self::Item) -> R,
+        R: TryOutput<Output = B>,
+    {
+        async move {
+            let mut accumulator = init;
+            while let Some(item) = self.next_async().await {
+                match f(accumulator, item).await.branch() {
+                    ControlFlow::Continue(next) => accumulator = next,
+                    ControlFlow::Break(residual) => return R::from_residual(residual),
+                }
+            }
+            R::from_output(accumulator)
+        }
+    }
+
+    /// Reduces the items to a single one by repeatedly applying an async closure, using the first
+    /// item as the initial accumulator.
+    ///
+    /// This is similar to the standard `Iterator::reduce`. Returns `None` if the iterator is
+    /// empty.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let longest = iter(["a", "abc", "ab"])
+    ///     .reduce_async(async |longest, item| if item.len() > longest.len() { item } else { longest })
+    ///     .await;
+    ///   assert_eq!(longest, Some("abc"));
+    ///
+    ///   let empty = iter(std::iter::empty::<&str>())
+    ///     .reduce_async(async |longest, _| longest)
+    ///     .await;
+    ///   assert_eq!(empty, None);
+    /// });
+    /// ```
+    fn reduce_async<F>(self, mut f: F) -> impl Future<Output = Option<Self::Item>>
+    where
+        Self: Sized,
+        F: AsyncFnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        async move {
+            let mut this = pin!(self);
+            let mut accumulator = next_pinned(this.as_mut()).await?;
+            while let Some(item) = next_pinned(this.as_mut()).await {
+                accumulator = f(accumulator, item).await;
+            }
+            Some(accumulator)
+        }
+    }
+
+    /// Tests whether an async predicate resolves to `true` for any item of the iterator.
+    ///
+    /// This is similar to the standard `Iterator::any`. It stops pulling items as soon as the
+    /// predicate resolves to `true`, and returns `false` for an empty iterator.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// async fn responds(host: &str) -> bool {
+    ///   // Simulate a health check
+    ///   task::yield_now().await;
+    ///   host.ends_with(".online")
+    /// }
+    ///
+    /// task::block_on(async {
+    ///   let hosts = ["a.offline", "b.online", "c.online"];
+    ///   let any_up = iter(hosts).any_async(async |host| responds(host).await).await;
+    ///   assert!(any_up);
+    /// });
+    /// ```
+    fn any_async<F>(&mut self, mut f: F) -> impl Future<Output = bool>
+    where
+        Self: Unpin,
+        F: AsyncFnMut(Self::Item) -> bool,
+    {
+        async move {
+            while let Some(item) = self.next_async().await {
+                if f(item).await {
+                    return true;
+                }
+            }
+            false
+        }
+    }
+
+    /// Tests whether an async predicate resolves to `true` for every item of the iterator.
+    ///
+    /// This is similar to the standard `Iterator::all`. It stops pulling items as soon as the
+    /// predicate resolves to `false`, and returns `true` for an empty iterator.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, AsyncIterator, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let mut items = iter([2, 4, 5, 6]);
+    ///   let all_even = items.all_async(async |item| item % 2 == 0).await;
+    ///
+    ///   assert!(!all_even);
+    ///   assert_eq!(items.next_async().await, Some(6));
+    /// });
+    /// ```
+    fn all_async<F>(&mut self, mut f: F) -> impl Future<Output = bool>
+    where
+        Self: Unpin,
+        F: AsyncFnMut(Self::Item) -> bool,
+    {
+        async move {
+            while let Some(item) = self.next_async().await {
+                if !f(item).await {
+                    return false;
+                }
+            }
+            true
+        }
+    }
+
+    /// Searches for the first item for which an async predicate, given a reference to the item,
+    /// resolves to `true`.
+    ///
+    /// This is similar to the standard `Iterator::find`. It stops pulling items as soon as a
+    /// match is found, and returns `None` if there is none.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let users = vec![("ada", false), ("grace", true), ("linus", true)];
+    ///   let first_admin = iter(users)
+    ///     .find_async(async |(_, is_admin)| {
+    ///       task::yield_now().await;
+    ///       *is_admin
+    ///     })
+    ///     .await;
+    ///
+    ///   assert_eq!(first_admin, Some(("grace", true)));
+    /// });
+    /// ```
+    fn find_async<F>(&mut self, mut f: F) -> impl Future<Output = Option<Self::Item>>
+    where
+        Self: Unpin,
+        F: AsyncFnMut(&Self::Item) -> bool,
+    {
+        async move {
+            while let Some(item) = self.next_async().await {
+                if f(&item).await {
+                    return Some(item);
+                }
+            }
+            None
+        }
+    }
+
+    /// Applies an async closure to the items of the iterator and returns the first `Some` value
+    /// it resolves to.
+    ///
+    /// This is similar to the standard `Iterator::find_map`. It stops pulling items as soon as
+    /// the closure resolves to `Some`, and returns `None` if it never does.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let first_number = iter(["lol", "NaN", "2", "5"])
+    ///     .find_map_async(async |input| input.parse::<u32>().ok())
+    ///     .await;
+    ///
+    ///   assert_eq!(first_number, Some(2));
+    /// });
+    /// ```
+    fn find_map_async<B, F>(&mut self, mut f: F) -> impl Future<Output = Option<B>>
+    where
+        Self: Unpin,
+        F: AsyncFnMut(Self::Item) -> Option<B>,
+    {
+        async move {
+            while let Some(item) = self.next_async().await {
+                if let Some(output) = f(item).await {
+                    return Some(output);
+                }
+            }
+            None
+        }
+    }
+
+    /// Searches for the first item for which an async predicate resolves to `true`, returning
+    /// its index.
+    ///
+    /// This is similar to the standard `Iterator::position`. It stops pulling items as soon as a
+    /// match is found, and returns `None` if there is none.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let position = iter([1, 3, 4, 6])
+    ///     .position_async(async |item| item % 2 == 0)
+    ///     .await;
+    ///
+    ///   assert_eq!(position, Some(2));
+    /// });
+    /// ```
+    fn position_async<F>(&mut self, mut f: F) -> impl Future<Output = Option<usize>>
+    where
+        Self: Unpin,
+        F: AsyncFnMut(Self::Item) -> bool,
+    {
+        async move {
+            let mut index = 0;
+            while let Some(item) = self.next_async().await {
+                if f(item).await {
+                    return Some(index);
+                }
+                index += 1;
+            }
+            None
+        }
+    }
+
+    /// Returns the `n`th item of the iterator, counting from zero.
+    ///
+    /// This is similar to the standard `Iterator::nth`. The items before it are pulled and
+    /// dropped, and nothing after it is pulled, so calling it again continues from the next item.
+    /// Returns `None` if the iterator has `n` items or fewer.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let mut items = iter([1, 2, 3, 4]);
+    ///
+    ///   assert_eq!(items.async_nth(1).await, Some(2));
+    ///   assert_eq!(items.async_nth(0).await, Some(3));
+    ///   assert_eq!(items.async_nth(1).await, None);
+    /// });
+    /// ```
+    fn async_nth(&mut self, n: usize) -> impl Future<Output = Option<Self::Item>>
+    where
+        Self: Unpin,
+    {
+        async move {
+            for _ in 0..n {
+                self.next_async().await?;
+            }
+            self.next_async().await
+        }
+    }
+
+    /// Consumes the iterator, returning its last item.
+    ///
+    /// This is similar to the standard `Iterator::last`. Every item is pulled, but only the most
+    /// recent one is kept. Returns `None` if the iterator is empty.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let last = iter([1, 2, 3])
+    ///     .map_async(|item| async move { item * 2 })
+    ///     .async_last()
+    ///     .await;
+    ///
+    ///   assert_eq!(last, Some(6));
+    /// });
+    /// ```
+    fn async_last(self) -> impl Future<Output = Option<Self::Item>>
+    where
+        Self: Sized,
+    {
+        async move {
+            let mut this = pin!(self);
+            let mut last = None;
+            while let Some(item) = next_pinned(this.as_mut()).await {
+                last = Some(item);
+            }
+            last
+        }
+    }
+
+    /// Consumes the iterator, counting the number of items it yields.
+    ///
+    /// This is similar to the standard `Iterator::count`. Every item is pulled, so all async work
+    /// behind the iterator runs, but no item is kept.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   let even = iter(1..=10)
+    ///     .filter_async(|item| async move { item % 2 == 0 })
+    ///     .async_count()
+    ///     .await;
+    ///
+    ///   assert_eq!(even, 5);
+    /// });
+    /// ```
+    fn async_count(self) -> impl Future<Output = usize>
+    where
+        Self: Sized,
+    {
+        async move {
+            let mut this = pin!(self);
+            let mut count = 0;
+            while next_pinned(this.as_mut()).await.is_some() {
+                count += 1;
+            }
+            count
+        }
+    }
+
+    /// Consumes the async iterator and returns a `ProcessResults` future that collects
+    /// successes and errors based on a specified strategy.
+    ///
+    /// This is a convenience method for turning an `AsyncIterator<Item = Result<T, E>>`
+    /// into a `ProcessResults`, which is a future that resolves to a
+    /// `ProcessResultsContainer<T, E>`.
+    ///
+    /// By default, the `ProcessResultsStrategy::Partition` strategy is used,
+    /// which means all items will be processed and separated into successes and errors.
+    /// You can change the strategy using `.with_process_strategy(...)`, and collect into other
+    /// collections than `Vec` using `.with_collections::<S, C>()`.
+    ///
+    /// # Type Parameters
+    /// - `T`: The success type inside the `Result`.
+    /// - `E`: The error type inside the `Result`.
+    ///
+    /// # Returns
+    /// A `ProcessResults<Self, T, E>` future that resolves to `ProcessResultsContainer<T, E>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{
+    ///   AsyncIterTools, AsyncIterator,
+    ///   iter::{iter, process_result::ProcessResultsStrategy},
+    /// };
+    /// use async_std::task;
+    ///
+    /// task::block_on(async {
+    ///   // Partition strategy: collect all successes and errors
+    ///   let results = iter(vec![Ok(1), Err("err1"), Ok(2), Err("err2")])
+    ///     .process_results::<i32, &str>()
+    ///     .await;
+    ///   assert_eq!(results.successes(), &vec![1, 2]);
+    ///   assert_eq!(results.errors(), &vec!["err1", "err2"]);
+    ///
+    ///   // Convert to result and unwrap error. OBS: Only gets the first error
+    ///   let first_result_err = results.into_result().unwrap_err();
+    ///   assert_eq!(first_result_err, "err1");
+    ///
+    ///   // Partition strategy: collect all successes
+    ///   let results = iter(vec![Ok(1), Ok(2)]).process_results::<i32, &str>().await;
+    ///   assert_eq!(results.successes(), &vec![1, 2]);
+    ///
+    ///   // Convert to result and unwrap
+    ///   let successes = results.into_result().unwrap();
+    ///   assert_eq!(successes, vec![1, 2]);
+    ///
+    ///   // BreakOnError strategy: stop at the first error
+    ///   let results = iter(vec![Ok(1), Err("early"), Ok(2)])
+    ///     .process_results::<i32, &str>()
+    ///     .with_process_strategy(ProcessResultsStrategy::BreakOnError)
+    ///     .await;
+    ///   assert_eq!(results.successes(), &vec![]); // did not continue after first error
+    ///   assert_eq!(results.errors(), &vec!["early"]);
+    ///
+    ///   // BreakOnErrorKeepSuccesses strategy: stop at the first error, keeping earlier successes
+    ///   let results = iter(vec![Ok(1), Err("early"), Ok(2)])
+    ///     .process_results::<i32, &str>()
+    ///     .with_process_strategy(ProcessResultsStrategy::BreakOnErrorKeepSuccesses)
+    ///     .await;
+    ///   assert_eq!(results.successes(), &vec![1]);
+    ///   assert_eq!(results.errors(), &vec!["early"]);
+    /// });
+    /// ```
+    fn process_results<T, E>(self) -> ProcessResults<Self, T, E>
+    where
+        Self: Sized + AsyncIterator<Item = Result<T, E>>,
+    {
+        ProcessResults::new(self)
+    }
+
+    /// Converts the async iterator into a [`Stream`](stream::Stream).
+    ///
+    /// The returned stream polls the iterator through
+    /// [`AsyncIterator::poll_next`], so it can be passed to any code built on `futures`.
+    ///
+    /// This method is only available with the `futures` feature enabled.
+    ///
+    /// ---
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use async_iter_ext::{AsyncIterTools, iter::iter};
+    /// use async_std::{stream::StreamExt, task};
+    ///
+    /// task::block_on(async {
+    ///   let mut stream = iter([1, 2, 3])
+    ///     .map_async(|item| async move { item * 2 })
+    ///     .into_stream();
+    ///
+    ///   assert_eq!(stream.next().await, Some(2));
+    ///   assert_eq!(stream.next().await, Some(4));
+    ///   assert_eq!(stream.next().await, Some(6));
+    ///   assert_eq!(stream.next().await, None);
+    /// });
+    /// ```
+    #[cfg(feature = "futures")]
+    fn into_stream(self) -> stream::IntoStream<Self>
+    where
+        Self: Sized,
+    {
+        stream::IntoStream::new(self)
     }
 }
 
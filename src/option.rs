@@ -1,6 +1,7 @@
 /// Asynchronous extension methods for `Option<T>`.
 ///
 /// This trait allows using asynchronous functions with `Option` types,
+/// including native async closures that mutate their captured state.
 pub trait AsyncOptionTools<T> {
     /// Asynchronously checks if the option is `Some` and satisfies a predicate.
     ///
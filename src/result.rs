@@ -1,4 +1,7 @@
 /// An extension trait for `Result<T, E>` providing asynchronous combinators.
+///
+/// Every combinator accepts a closure returning a future, as well as a native async closure that
+/// mutates its captured state.
 pub trait AsyncResultTools<T, E> {
     /// Asynchronously evaluates whether the result is `Ok` and satisfies a given predicate.
     ///
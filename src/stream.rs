@@ -0,0 +1,133 @@
+//! Interoperability with the [`Stream`] trait from `futures-core`.
+//!
+//! This module is only available with the `futures` feature enabled.
+
+use std::{
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+pub use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::AsyncIterator;
+
+/// Creates an async iterator that yields the items of a [`Stream`].
+///
+/// ---
+///
+/// # Examples
+///
+/// ```rust
+/// use async_iter_ext::{AsyncIterTools, AsyncIterator, stream::from_stream};
+/// use async_std::{stream, task};
+///
+/// task::block_on(async {
+///   let doubled = from_stream(stream::from_iter([1, 2, 3]))
+///     .map_async(|item| async move { item * 2 })
+///     .async_collect::<Vec<_>>()
+///     .await;
+///
+///   assert_eq!(doubled, vec![2, 4, 6]);
+/// });
+/// ```
+pub fn from_stream<S>(stream: S) -> FromStream<S>
+where
+    S: Stream,
+{
+    FromStream { stream }
+}
+
+pin_project! {
+    /// An async iterator over the items of a [`Stream`].
+    ///
+    /// This struct is created by the [`from_stream`] function.
+    #[must_use = "async iterators are lazy and do nothing unless consumed"]
+    pub struct FromStream<S> {
+        #[pin]
+        stream: S,
+    }
+}
+
+impl<S> FromStream<S> {
+    /// Consumes the adapter and returns the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S> AsyncIterator for FromStream<S>
+where
+    S: Stream,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx)
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S> Debug for FromStream<S>
+where
+    S: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FromStream")
+            .field("stream", &self.stream)
+            .finish()
+    }
+}
+
+pin_project! {
+    /// A [`Stream`] over the items of an async iterator.
+    ///
+    /// This struct is created by the `.into_stream()` method on `AsyncIterTools`.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct IntoStream<I> {
+        #[pin]
+        iter: I,
+    }
+}
+
+impl<I> IntoStream<I> {
+    /// Creates a new `IntoStream` yielding the items of `iter`.
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+
+    /// Consumes the adapter and returns the underlying async iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I> Stream for IntoStream<I>
+where
+    I: AsyncIterator,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().iter.poll_next(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.async_size_hint()
+    }
+}
+
+impl<I> Debug for IntoStream<I>
+where
+    I: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IntoStream")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
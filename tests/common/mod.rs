@@ -0,0 +1,86 @@
+//! Sources and assertions shared by the integration tests.
+//!
+//! Every test crate only uses a few of them.
+#![allow(dead_code)]
+
+use std::{
+    cell::Cell,
+    ops::RangeInclusive,
+    pin::Pin,
+    task::{Context, Poll, ready},
+    time::Duration,
+    vec::IntoIter,
+};
+
+use async_iter_ext::iter::{AsyncIterator, Iter, iter, poll_fn};
+use async_std::task::sleep;
+
+/// An async iterator that waits on a timer before yielding each item of the given iterator.
+///
+/// The timer is kept in the iterator between polls, so the source only makes progress if callers
+/// keep polling it instead of restarting the pull.
+pub struct Delayed<I> {
+    items: I,
+    timer: Option<Pin<Box<dyn Future<Output = ()> + Send>>>,
+}
+
+impl<I> Delayed<I>
+where
+    I: Iterator,
+{
+    pub fn new(items: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            items: items.into_iter(),
+            timer: None,
+        }
+    }
+}
+
+impl<I> AsyncIterator for Delayed<I>
+where
+    I: Iterator + Unpin,
+{
+    type Item = I::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let timer = this
+            .timer
+            .get_or_insert_with(|| Box::pin(sleep(Duration::from_millis(10))));
+        ready!(timer.as_mut().poll(cx));
+        this.timer = None;
+        Poll::Ready(this.items.next())
+    }
+}
+
+/// Creates a [`Delayed`] source yielding the numbers `1..=count`.
+pub fn ticker(count: u32) -> Delayed<RangeInclusive<u32>> {
+    Delayed::new(1..=count)
+}
+
+/// Creates a source yielding `items` that records how many times it has been pulled from.
+pub fn counted<'a, I>(
+    items: I,
+    pulls: &'a Cell<usize>,
+) -> impl AsyncIterator<Item = I::Item> + Unpin + 'a
+where
+    I: IntoIterator,
+    I::IntoIter: 'a,
+{
+    let mut items = items.into_iter();
+    poll_fn(move |_cx| {
+        pulls.set(pulls.get() + 1);
+        Poll::Ready(items.next())
+    })
+}
+
+/// Asserts that an adapter reports `(0, Some(0))` over an empty source, and `expected` over a
+/// source of three items.
+pub fn assert_size_hint<A, F>(adapt: F, expected: (usize, Option<usize>))
+where
+    F: Fn(Iter<IntoIter<u32>>) -> A,
+    A: AsyncIterator,
+{
+    assert_eq!(adapt(iter(vec![])).async_size_hint(), (0, Some(0)));
+    assert_eq!(adapt(iter(vec![1, 2, 3])).async_size_hint(), expected);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "blanket-iterator")]
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use async_iter_ext::{AsyncIterTools, iter::AsyncIterator};
+use async_std::task::sleep;
+use rstest::rstest;
+
+#[rstest]
+async fn test_blanket_std_iterator_through_combinators() {
+    let items = [1, 2, 3, 4]
+        .iter()
+        .map_async(|item| async move {
+            sleep(Duration::from_millis(10)).await;
+            item * 2
+        })
+        .filter_async(|item| async move { item > 2 })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(items, vec![4, 6, 8]);
+}
+
+#[rstest]
+fn test_blanket_poll_next_and_size_hint() {
+    let mut items = (0..2).map(|item| item * 10);
+    assert_eq!(items.async_size_hint(), (2, Some(2)));
+
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(
+        Pin::new(&mut items).poll_next(&mut cx),
+        Poll::Ready(Some(0))
+    );
+    assert_eq!(
+        Pin::new(&mut items).poll_next(&mut cx),
+        Poll::Ready(Some(10))
+    );
+    assert_eq!(Pin::new(&mut items).poll_next(&mut cx), Poll::Ready(None));
+}
+
+#[rstest]
+async fn test_blanket_std_iterator_usable_after_async_terminal() {
+    let mut items = [1, 2, 3, 4].into_iter();
+
+    assert_eq!(items.position_async(async |item| item == 2).await, Some(1));
+    assert_eq!(items.next(), Some(3));
+    assert_eq!(items.next_async().await, Some(4));
+}
@@ -0,0 +1,54 @@
+use std::cell::Cell;
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use common::counted;
+use rstest::rstest;
+
+mod common;
+
+#[rstest]
+#[case(vec![], vec![], vec![])]
+#[case(vec![1, 2], vec![], vec![1, 2])]
+#[case(vec![], vec![3], vec![3])]
+#[case(vec![1, 2], vec![3, 4], vec![1, 2, 3, 4])]
+async fn test_chain(#[case] first: Vec<u32>, #[case] second: Vec<u32>, #[case] expected: Vec<u32>) {
+    let chained = iter(first)
+        .async_chain(iter(second).map_async(|item| async move { item }))
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(chained, expected);
+}
+
+#[rstest]
+async fn test_chain_does_not_poll_first_after_exhausted() {
+    let pulls = Cell::new(0);
+    let chained = counted([1], &pulls)
+        .async_chain(iter([2, 3]))
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(chained, vec![1, 2, 3]);
+    assert_eq!(pulls.get(), 2);
+}
+
+#[rstest]
+async fn test_chain_size_hint() {
+    let mut chained = iter(0..2).async_chain(iter(0..3));
+    assert_eq!(chained.async_size_hint(), (5, Some(5)));
+
+    chained.next_async().await;
+    chained.next_async().await;
+    chained.next_async().await;
+    assert_eq!(chained.async_size_hint(), (2, Some(2)));
+}
+
+#[rstest]
+fn test_chain_size_hint_unbounded() {
+    let chained = iter(0..2).async_chain(iter(0..));
+
+    assert_eq!(chained.async_size_hint(), (usize::MAX, None));
+}
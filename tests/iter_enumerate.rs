@@ -0,0 +1,38 @@
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use rstest::rstest;
+
+#[rstest]
+#[case(vec![], vec![])]
+#[case(vec!["a", "b", "c"], vec![(0, "a"), (1, "b"), (2, "c")])]
+async fn test_enumerate(#[case] items: Vec<&str>, #[case] expected: Vec<(usize, &str)>) {
+    let enumerated = iter(items)
+        .map_async(|item| async move { item })
+        .async_enumerate()
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(enumerated, expected);
+}
+
+#[rstest]
+async fn test_enumerate_counts_yielded_items_only() {
+    let enumerated = iter(1..=6)
+        .filter_async(|item| async move { item % 2 == 0 })
+        .async_enumerate()
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(enumerated, vec![(0, 2), (1, 4), (2, 6)]);
+}
+
+#[rstest]
+async fn test_enumerate_size_hint() {
+    let mut enumerated = iter(0..4).async_enumerate();
+    assert_eq!(enumerated.async_size_hint(), (4, Some(4)));
+
+    enumerated.next_async().await;
+    assert_eq!(enumerated.async_size_hint(), (3, Some(3)));
+}
@@ -1,15 +1,21 @@
-use std::time::Duration;
+use std::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
 
-use async_iter_ext::{AsyncIterTools, iter::AsyncIterator};
-use async_std::task::sleep;
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::{sleep, yield_now};
 use rstest::rstest;
 
 #[rstest]
 async fn test_simple_filter() {
     let items = vec![1, 2, 3];
 
-    let filtered_items = items
-        .into_iter()
+    let filtered_items = iter(items)
         .filter_async(|i| async move {
             sleep(Duration::from_millis(100)).await;
             i == 2
@@ -24,8 +30,7 @@ async fn test_simple_filter() {
 async fn test_multiple_filters() {
     let items = vec![1, 2, 3];
 
-    let filtered_items = items
-        .into_iter()
+    let filtered_items = iter(items)
         .filter_async(|i| async move {
             sleep(Duration::from_millis(100)).await;
             i > 1
@@ -45,8 +50,7 @@ async fn test_multiple_filters() {
 async fn test_async_filter_then_sync_filter() {
     let items = vec![1, 2, 3];
 
-    let filtered_items = items
-        .into_iter()
+    let filtered_items = iter(items)
         .filter_async(|i| async move {
             sleep(Duration::from_millis(100)).await;
             i > 1
@@ -57,3 +61,49 @@ async fn test_async_filter_then_sync_filter() {
 
     assert_eq!(filtered_items, vec![2]);
 }
+
+#[rstest]
+async fn test_filter_without_upper_size_hint() {
+    let mut items = vec![1, 2, 3, 4, 5, 6].into_iter();
+    let source = std::iter::from_fn(move || items.next());
+    assert_eq!(source.size_hint(), (0, None));
+
+    let filtered_items = iter(source)
+        .filter_async(|i| async move { i % 2 == 0 })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(filtered_items, vec![2, 4, 6]);
+}
+
+#[rstest]
+async fn test_filter_next_async_skips_rejected_items() {
+    let mut seen = vec![];
+
+    let mut filtered = iter([1, 2, 3, 4]).filter_async(|i| async move { i > 2 });
+    while let Some(item) = filtered.next_async().await {
+        seen.push(item);
+    }
+
+    assert_eq!(seen, vec![3, 4]);
+}
+
+#[rstest]
+fn test_filter_poll_resumes_after_pending() {
+    let mut filtered = iter([1, 2, 3, 4]).filter_async(|i| async move {
+        yield_now().await;
+        i % 2 == 0
+    });
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 0;
+    let filtered_items = loop {
+        polls += 1;
+        if let Poll::Ready(items) = Pin::new(&mut filtered).poll(&mut cx) {
+            break items.collect::<Vec<_>>();
+        }
+    };
+
+    assert_eq!(filtered_items, vec![2, 4]);
+    assert_eq!(polls, 5);
+}
@@ -0,0 +1,104 @@
+use std::{collections::HashSet, time::Duration};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::{sleep, spawn};
+use common::assert_size_hint;
+use rstest::rstest;
+
+mod common;
+
+/// An item that deliberately does not implement `Clone`.
+#[derive(Debug, PartialEq)]
+struct Handle(u32);
+
+impl Handle {
+    async fn is_even(&self) -> bool {
+        sleep(Duration::from_millis(10)).await;
+        self.0.is_multiple_of(2)
+    }
+}
+
+#[rstest]
+async fn test_filter_ref_keeps_non_clone_items() {
+    let filtered_items = iter((1..=6).map(Handle))
+        .filter_async_ref(|handle: &Handle| {
+            let id = handle.0;
+            async move {
+                sleep(Duration::from_millis(10)).await;
+                id.is_multiple_of(2)
+            }
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(filtered_items, vec![Handle(2), Handle(4), Handle(6)]);
+}
+
+#[rstest]
+async fn test_filter_ref_future_borrows_item() {
+    let filtered_items = iter((1..=6).map(Handle))
+        .filter_async_ref(async |handle| handle.is_even().await)
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(filtered_items, vec![Handle(2), Handle(4), Handle(6)]);
+}
+
+#[rstest]
+async fn test_filter_ref_does_not_clone_items() {
+    let items = vec![vec![1; 3], vec![], vec![2; 2]];
+    let pointers = items.iter().map(|item| item.as_ptr()).collect::<Vec<_>>();
+
+    let filtered_items = iter(items)
+        .filter_async_ref(async |item| !item.is_empty())
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(filtered_items, vec![vec![1; 3], vec![2; 2]]);
+    assert_eq!(filtered_items[0].as_ptr(), pointers[0]);
+    assert_eq!(filtered_items[1].as_ptr(), pointers[2]);
+}
+
+#[rstest]
+async fn test_filter_ref_borrows_captured_state() {
+    let mut seen = HashSet::new();
+
+    let unique = iter([1, 2, 1, 3, 2, 4])
+        .filter_async_ref(async |item| {
+            sleep(Duration::from_millis(10)).await;
+            seen.insert(*item)
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(unique, vec![1, 2, 3, 4]);
+    assert_eq!(seen.len(), 4);
+}
+
+#[rstest]
+async fn test_filter_ref_runs_on_spawned_task() {
+    let unique = spawn(async {
+        let mut seen = HashSet::new();
+        iter([1, 2, 1, 3])
+            .filter_async_ref(async move |item| {
+                sleep(Duration::from_millis(1)).await;
+                seen.insert(*item)
+            })
+            .async_collect::<Vec<_>>()
+            .await
+    })
+    .await;
+
+    assert_eq!(unique, vec![1, 2, 3]);
+}
+
+#[rstest]
+fn test_filter_ref_size_hint() {
+    assert_size_hint(
+        |items| items.filter_async_ref(async |item| *item > 1),
+        (0, Some(3)),
+    );
+}
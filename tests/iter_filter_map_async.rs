@@ -0,0 +1,74 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::{sleep, yield_now};
+use common::assert_size_hint;
+use rstest::rstest;
+
+mod common;
+
+#[rstest]
+async fn test_filter_map_yields_only_some_values() {
+    let items = ["1", "two", "3", "four"];
+
+    let parsed_items = iter(items)
+        .filter_map_async(|item| async move {
+            sleep(Duration::from_millis(10)).await;
+            item.parse::<u32>().ok()
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(parsed_items, vec![1, 3]);
+}
+
+#[rstest]
+async fn test_filter_map_matches_sync_filter_map() {
+    let items = 0..20;
+
+    let expected = items
+        .clone()
+        .filter_map(|item| (item % 3 == 0).then_some(item * 10))
+        .collect::<Vec<_>>();
+    let mapped_items = iter(items)
+        .filter_map_async(|item| async move { (item % 3 == 0).then_some(item * 10) })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(mapped_items, expected);
+}
+
+#[rstest]
+fn test_filter_map_size_hint() {
+    assert_size_hint(
+        |items| items.filter_map_async(|item| std::future::ready(Some(item))),
+        (0, Some(3)),
+    );
+}
+
+#[rstest]
+fn test_filter_map_poll_resumes_after_pending() {
+    let mut mapped = iter([1, 2, 3, 4]).filter_map_async(|item| async move {
+        yield_now().await;
+        (item % 2 == 0).then_some(item)
+    });
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut items = vec![];
+    loop {
+        match Pin::new(&mut mapped).poll_next(&mut cx) {
+            Poll::Ready(Some(item)) => items.push(item),
+            Poll::Ready(None) => break,
+            Poll::Pending => {}
+        }
+    }
+
+    assert_eq!(items, vec![2, 4]);
+}
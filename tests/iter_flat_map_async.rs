@@ -0,0 +1,150 @@
+use std::{cell::Cell, time::Duration};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use common::counted;
+use rstest::rstest;
+
+mod common;
+
+/// Simulates fetching a page of records for a tenant.
+async fn fetch_records(tenant: &str, count: usize) -> Vec<String> {
+    sleep(Duration::from_millis(10)).await;
+    (1..=count)
+        .map(|index| format!("{tenant}-{index}"))
+        .collect()
+}
+
+#[rstest]
+async fn test_flat_map_iter_async_over_fetched_pages() {
+    let records = iter([("acme", 2), ("empty", 0), ("globex", 1)])
+        .flat_map_iter_async(|(tenant, count)| fetch_records(tenant, count))
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(records, vec!["acme-1", "acme-2", "globex-1"]);
+}
+
+#[rstest]
+async fn test_flat_map_iter_async_pulls_outer_lazily() {
+    let pulls = Cell::new(0);
+    let mut flattened =
+        counted([2, 3], &pulls).flat_map_iter_async(|count| async move { 0..count });
+
+    assert_eq!(flattened.next_async().await, Some(0));
+    assert_eq!(flattened.next_async().await, Some(1));
+    assert_eq!(pulls.get(), 1);
+    assert_eq!(flattened.next_async().await, Some(0));
+    assert_eq!(pulls.get(), 2);
+}
+
+#[rstest]
+async fn test_flat_map_iter_async_size_hint() {
+    let mut flattened = iter([3]).flat_map_iter_async(|count| async move { vec![0; count] });
+    assert_eq!(flattened.async_size_hint(), (0, None));
+
+    flattened.next_async().await;
+    assert_eq!(flattened.async_size_hint(), (2, Some(2)));
+}
+
+#[rstest]
+async fn test_flat_map_async_to_async_iterators() {
+    let doubled = iter([vec![1, 2], vec![3]])
+        .flat_map_async(|page| async move {
+            iter(page).filter_map_async(|item| async move {
+                sleep(Duration::from_millis(1)).await;
+                Some(item * 2)
+            })
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(doubled, vec![2, 4, 6]);
+}
+
+#[rstest]
+async fn test_flat_map_async_pulls_outer_lazily() {
+    let pulls = Cell::new(0);
+    let mut flattened =
+        counted([2, 3], &pulls).flat_map_async(|count| async move { iter(0..count) });
+
+    assert_eq!(flattened.next_async().await, Some(0));
+    assert_eq!(flattened.next_async().await, Some(1));
+    assert_eq!(pulls.get(), 1);
+    assert_eq!(flattened.next_async().await, Some(0));
+    assert_eq!(pulls.get(), 2);
+}
+
+#[rstest]
+async fn test_flat_map_async_size_hint() {
+    let mut flattened = iter([3]).flat_map_async(|count| async move { iter(0..count) });
+    assert_eq!(flattened.async_size_hint(), (0, None));
+
+    flattened.next_async().await;
+    assert_eq!(flattened.async_size_hint(), (2, Some(2)));
+}
+
+#[rstest]
+#[case(vec![], vec![])]
+#[case(vec![vec![], vec![]], vec![])]
+#[case(vec![vec![1, 2], vec![], vec![3]], vec![1, 2, 3])]
+async fn test_flatten(#[case] pages: Vec<Vec<u32>>, #[case] expected: Vec<u32>) {
+    let flattened = iter(pages)
+        .map_async(|page| async move { iter(page) })
+        .async_flatten()
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(flattened, expected);
+}
+
+#[rstest]
+#[case(vec![], vec![])]
+#[case(vec![vec![], vec![]], vec![])]
+#[case(vec![vec![1, 2], vec![], vec![3]], vec![1, 2, 3])]
+async fn test_flatten_iter(#[case] pages: Vec<Vec<u32>>, #[case] expected: Vec<u32>) {
+    let flattened = iter(pages)
+        .map_async(|page| async move { page })
+        .async_flatten_iter()
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(flattened, expected);
+}
+
+#[rstest]
+async fn test_flatten_iter_size_hint() {
+    let mut flattened = iter([0..2, 0..3]).async_flatten_iter();
+    assert_eq!(flattened.async_size_hint(), (0, None));
+
+    flattened.next_async().await;
+    flattened.next_async().await;
+    flattened.next_async().await;
+    assert_eq!(flattened.async_size_hint(), (2, Some(2)));
+}
+
+#[rstest]
+async fn test_flatten_nested_async_iterators() {
+    let flattened = iter(
+        [1, 2].map(|count| iter(0..count).map_async(move |item| async move { (count, item) })),
+    )
+    .async_flatten()
+    .async_collect::<Vec<_>>()
+    .await;
+
+    assert_eq!(flattened, vec![(1, 0), (2, 0), (2, 1)]);
+}
+
+#[rstest]
+async fn test_flatten_size_hint() {
+    let mut flattened = iter([iter(0..2), iter(0..3)]).async_flatten();
+    assert_eq!(flattened.async_size_hint(), (0, None));
+
+    flattened.next_async().await;
+    flattened.next_async().await;
+    flattened.next_async().await;
+    assert_eq!(flattened.async_size_hint(), (2, Some(2)));
+}
@@ -0,0 +1,117 @@
+use std::{ops::ControlFlow, time::Duration};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{iter, poll_fn},
+};
+use async_std::task::sleep;
+use rstest::rstest;
+
+/// Simulates fetching one page of results from a paginated API.
+async fn fetch_page(page: usize) -> Vec<u32> {
+    sleep(Duration::from_millis(10)).await;
+    vec![page as u32; page]
+}
+
+#[rstest]
+async fn test_fold_async_running_total_over_pages() {
+    let total = iter(1..=3)
+        .fold_async(0, async |total, page| {
+            total + fetch_page(page).await.iter().sum::<u32>()
+        })
+        .await;
+
+    assert_eq!(total, 1 + 2 * 2 + 3 * 3);
+}
+
+#[rstest]
+#[case(vec![], 10)]
+#[case(vec![1, 2, 3], 16)]
+async fn test_fold_async_matches_sync_fold(#[case] items: Vec<u32>, #[case] expected: u32) {
+    let folded = iter(items.clone())
+        .fold_async(10, async |total, item| total + item)
+        .await;
+
+    assert_eq!(
+        folded,
+        items.into_iter().fold(10, |total, item| total + item)
+    );
+    assert_eq!(folded, expected);
+}
+
+#[rstest]
+async fn test_fold_async_does_not_collect_source() {
+    let mut pulled = 0;
+    let source = poll_fn(|_cx| {
+        pulled += 1;
+        std::task::Poll::Ready((pulled <= 1000).then_some(1u64))
+    });
+
+    let total = source
+        .fold_async(0u64, async |total, item| total + item)
+        .await;
+
+    assert_eq!(total, 1000);
+}
+
+#[rstest]
+#[case(vec![Ok(1), Ok(2), Ok(3)], Ok(6))]
+#[case(vec![Ok(1), Err("bad"), Ok(3)], Err("bad"))]
+#[case(vec![], Ok(0))]
+async fn test_try_fold_async_result(
+    #[case] items: Vec<Result<u32, &'static str>>,
+    #[case] expected: Result<u32, &'static str>,
+) {
+    let folded = iter(items)
+        .try_fold_async(0, async |total, item| Ok(total + item?))
+        .await;
+
+    assert_eq!(folded, expected);
+}
+
+#[rstest]
+async fn test_try_fold_async_short_circuits_on_none() {
+    let mut items = iter([1u8, 100, 200, 3, 4]);
+    let mut calls = 0;
+
+    let sum = items
+        .try_fold_async(0u8, async |total, item| {
+            calls += 1;
+            total.checked_add(item)
+        })
+        .await;
+
+    assert_eq!(sum, None);
+    assert_eq!(calls, 3);
+    assert_eq!(items.into_inner().collect::<Vec<_>>(), vec![3, 4]);
+}
+
+#[rstest]
+async fn test_try_fold_async_control_flow() {
+    let found = iter(1..)
+        .try_fold_async(0, async |total, item| {
+            if total + item > 10 {
+                ControlFlow::Break(item)
+            } else {
+                ControlFlow::Continue(total + item)
+            }
+        })
+        .await;
+
+    assert_eq!(found, ControlFlow::Break(5));
+}
+
+#[rstest]
+#[case(vec![], None)]
+#[case(vec![7], Some(7))]
+#[case(vec![3, 9, 4], Some(9))]
+async fn test_reduce_async(#[case] items: Vec<u32>, #[case] expected: Option<u32>) {
+    let reduced = iter(items)
+        .reduce_async(async |max, item| {
+            sleep(Duration::from_millis(1)).await;
+            max.max(item)
+        })
+        .await;
+
+    assert_eq!(reduced, expected);
+}
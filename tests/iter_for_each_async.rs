@@ -1,6 +1,6 @@
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
-use async_iter_ext::AsyncIterTools;
+use async_iter_ext::{AsyncIterTools, iter::iter};
 use async_std::task::sleep;
 
 #[rstest::rstest]
@@ -8,8 +8,7 @@ async fn test_for_each_async_mut() {
     let items = [1, 2, 3, 4];
 
     let mut mut_items = items;
-    mut_items
-        .iter_mut()
+    iter(mut_items.iter_mut())
         .for_each_async(|item| async move {
             sleep(Duration::from_millis(100)).await;
             *item += 3;
@@ -19,3 +18,17 @@ async fn test_for_each_async_mut() {
     assert_eq!(mut_items.len(), items.len());
     assert_eq!(mut_items, [4, 5, 6, 7]);
 }
+
+#[rstest::rstest]
+async fn test_for_each_async_mut_closure_borrows_state_mutably() {
+    let mut cache = HashMap::new();
+
+    iter([("a", 1), ("b", 2), ("a", 3)])
+        .for_each_async_mut(async |(key, value)| {
+            sleep(Duration::from_millis(10)).await;
+            *cache.entry(key).or_insert(0) += value;
+        })
+        .await;
+
+    assert_eq!(cache, HashMap::from([("a", 4), ("b", 2)]));
+}
@@ -0,0 +1,49 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use async_iter_ext::{AsyncIterTools, iter::iter};
+use async_std::task::sleep;
+use rstest::rstest;
+
+#[rstest]
+#[timeout(Duration::from_millis(300))]
+async fn test_for_each_concurrent_runs_closures_concurrently() {
+    let items = [1, 2, 3, 4];
+
+    let mut mut_items = items;
+    iter(mut_items.iter_mut())
+        .for_each_concurrent(4, |item| async move {
+            sleep(Duration::from_millis(100)).await;
+            *item += 3;
+        })
+        .await;
+
+    assert_eq!(mut_items, [4, 5, 6, 7]);
+}
+
+#[rstest]
+async fn test_for_each_concurrent_waits_for_all_in_flight() {
+    let finished = AtomicUsize::new(0);
+    let running = AtomicUsize::new(0);
+    let max_running = AtomicUsize::new(0);
+
+    iter(0..10)
+        .for_each_concurrent(3, |item| {
+            let finished = &finished;
+            let running = &running;
+            let max_running = &max_running;
+            async move {
+                let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now_running, Ordering::SeqCst);
+                sleep(Duration::from_millis(10 * (item % 3 + 1))).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                finished.fetch_add(1, Ordering::SeqCst);
+            }
+        })
+        .await;
+
+    assert_eq!(finished.load(Ordering::SeqCst), 10);
+    assert_eq!(max_running.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use rstest::rstest;
+
+#[rstest]
+async fn test_iter_from_collection() {
+    let items = iter(vec![1, 2, 3, 4]);
+    assert_eq!(items.async_size_hint(), (4, Some(4)));
+
+    let mapped_items = items
+        .map_async(|item| async move {
+            sleep(Duration::from_millis(10)).await;
+            item * 2
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(mapped_items, vec![2, 4, 6, 8]);
+}
+
+#[rstest]
+async fn test_iter_next_async() {
+    let mut items = iter(["a", "b"]);
+
+    assert_eq!(items.next_async().await, Some("a"));
+    assert_eq!(items.next_async().await, Some("b"));
+    assert_eq!(items.next_async().await, None);
+    assert_eq!(items.into_inner().count(), 0);
+}
@@ -0,0 +1,46 @@
+use std::{cell::Cell, task::Poll};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter, poll_fn},
+};
+use rstest::rstest;
+
+#[rstest]
+async fn test_fuse_returns_none_forever() {
+    let pulls = Cell::new(0);
+    // Yields an item on every odd pull and `None` on every even pull.
+    let flaky = poll_fn(|_cx| {
+        pulls.set(pulls.get() + 1);
+        Poll::Ready((pulls.get() % 2 == 1).then_some(pulls.get()))
+    });
+    let mut fused = flaky.async_fuse();
+
+    assert_eq!(fused.next_async().await, Some(1));
+    for _ in 0..3 {
+        assert_eq!(fused.next_async().await, None);
+    }
+    assert_eq!(pulls.get(), 2);
+}
+
+#[rstest]
+async fn test_fuse_after_filter() {
+    let mut fused = iter([1, 2, 3])
+        .filter_async(|item| async move { item > 1 })
+        .async_fuse();
+
+    assert_eq!(fused.next_async().await, Some(2));
+    assert_eq!(fused.next_async().await, Some(3));
+    assert_eq!(fused.next_async().await, None);
+    assert_eq!(fused.next_async().await, None);
+}
+
+#[rstest]
+async fn test_fuse_size_hint() {
+    let mut fused = iter(0..1).async_fuse();
+    assert_eq!(fused.async_size_hint(), (1, Some(1)));
+
+    fused.next_async().await;
+    fused.next_async().await;
+    assert_eq!(fused.async_size_hint(), (0, Some(0)));
+}
@@ -0,0 +1,59 @@
+use std::{cell::RefCell, time::Duration};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use common::assert_size_hint;
+use rstest::rstest;
+
+mod common;
+
+#[rstest]
+async fn test_inspect_async_runs_side_effect_in_order() {
+    let log = RefCell::new(vec![]);
+
+    let items = iter([3, 1, 2])
+        .inspect_async(|item| {
+            let item = *item;
+            let log = &log;
+            async move {
+                sleep(Duration::from_millis(item * 10)).await;
+                log.borrow_mut().push(item);
+            }
+        })
+        .map_async(|item| async move { item * 2 })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(items, vec![6, 2, 4]);
+    assert_eq!(log.into_inner(), vec![3, 1, 2]);
+}
+
+#[rstest]
+async fn test_inspect_async_awaits_side_effect_before_yielding() {
+    let log = RefCell::new(vec![]);
+    let mut inspected = iter([1, 2]).inspect_async(|item| {
+        let item = *item;
+        let log = &log;
+        async move {
+            sleep(Duration::from_millis(10)).await;
+            log.borrow_mut().push(item);
+        }
+    });
+
+    assert_eq!(inspected.next_async().await, Some(1));
+    assert_eq!(*log.borrow(), vec![1]);
+    assert_eq!(inspected.next_async().await, Some(2));
+    assert_eq!(inspected.next_async().await, None);
+    assert_eq!(*log.borrow(), vec![1, 2]);
+}
+
+#[rstest]
+fn test_inspect_async_size_hint() {
+    assert_size_hint(
+        |items| items.inspect_async(|_| std::future::ready(())),
+        (3, Some(3)),
+    );
+}
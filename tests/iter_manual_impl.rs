@@ -0,0 +1,92 @@
+#![cfg(not(feature = "blanket-iterator"))]
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use rstest::rstest;
+
+/// A countdown that is both a standard iterator and, through its own implementation, an async
+/// iterator. The two only coexist when the blanket implementation is disabled.
+#[derive(Debug)]
+struct Countdown {
+    remaining: u32,
+}
+
+impl Countdown {
+    fn new(from: u32) -> Self {
+        Self { remaining: from }
+    }
+}
+
+impl Iterator for Countdown {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.remaining;
+        self.remaining = current.checked_sub(1)?;
+        Some(current)
+    }
+}
+
+/// Yields the same numbers as the synchronous countdown, but doubled, so the tests can tell which
+/// implementation is being driven.
+impl AsyncIterator for Countdown {
+    type Item = u32;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(Iterator::next(self.get_mut()).map(|item| item * 2))
+    }
+
+    fn async_size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+#[rstest]
+fn test_manual_impl_sync_iteration_is_untouched() {
+    let items: Vec<_> = Countdown::new(3).collect();
+    assert_eq!(items, vec![3, 2, 1]);
+}
+
+#[rstest]
+async fn test_manual_impl_next_async() {
+    let mut countdown = Countdown::new(2);
+    assert_eq!(countdown.async_size_hint(), (2, Some(2)));
+
+    assert_eq!(countdown.next_async().await, Some(4));
+    assert_eq!(countdown.next_async().await, Some(2));
+    assert_eq!(countdown.next_async().await, None);
+}
+
+#[rstest]
+async fn test_manual_impl_through_combinators() {
+    let items = Countdown::new(4)
+        .filter_async(|item| async move { item > 2 })
+        .map_async(|item| async move {
+            sleep(Duration::from_millis(10)).await;
+            item + 1
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(items, vec![9, 7, 5]);
+}
+
+#[rstest]
+async fn test_manual_impl_alongside_iter_wrapper() {
+    let pairs = Countdown::new(3)
+        .async_zip(iter(Countdown::new(3)))
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(pairs, vec![(6, 3), (4, 2), (2, 1)]);
+}
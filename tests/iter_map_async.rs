@@ -1,7 +1,16 @@
-use std::{ops::Mul, time::Duration};
+use std::{
+    ops::Mul,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
 
-use async_iter_ext::{AsyncIterTools, iter::AsyncIterator};
-use async_std::task::sleep;
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::{sleep, yield_now};
 use rstest::rstest;
 
 async fn multiply_item<T: Mul<i32, Output = i32>>(value: T) -> i32 {
@@ -13,8 +22,7 @@ async fn multiply_item<T: Mul<i32, Output = i32>>(value: T) -> i32 {
 async fn test_single_multiply() {
     let items = [1, 2, 3, 4];
 
-    let mapped_items_vec = items
-        .iter()
+    let mapped_items_vec = iter(&items)
         .map_async(multiply_item)
         .async_collect::<Vec<_>>()
         .await;
@@ -27,8 +35,7 @@ async fn test_single_multiply() {
 async fn test_multiple_multiply() {
     let items = [1, 2, 3, 4];
 
-    let mapped_items_vec = items
-        .iter()
+    let mapped_items_vec = iter(&items)
         .map_async(multiply_item)
         .map_async(multiply_item)
         .map_async(multiply_item)
@@ -40,12 +47,11 @@ async fn test_multiple_multiply() {
 }
 
 #[rstest]
-#[timeout(Duration::from_millis(400))]
+#[timeout(Duration::from_millis(600))]
 async fn test_async_map_then_sync_map() {
     let items = [1, 2, 3, 4];
 
-    let async_and_then_sync = items
-        .iter()
+    let async_and_then_sync = iter(&items)
         .map_async(multiply_item)
         .await
         .map(|item| item * 2)
@@ -54,3 +60,63 @@ async fn test_async_map_then_sync_map() {
     assert_eq!(async_and_then_sync.len(), items.len());
     assert_eq!(async_and_then_sync, vec![4, 8, 12, 16]);
 }
+
+#[rstest]
+fn test_async_map_poll_resumes_after_pending() {
+    let mut mapped = iter([1, 2, 3]).map_async(|item| async move {
+        yield_now().await;
+        item * 2
+    });
+
+    let mut cx = Context::from_waker(Waker::noop());
+    let mut polls = 0;
+    let mapped_items = loop {
+        polls += 1;
+        if let Poll::Ready(items) = Pin::new(&mut mapped).poll(&mut cx) {
+            break items.collect::<Vec<_>>();
+        }
+    };
+
+    assert_eq!(mapped_items, vec![2, 4, 6]);
+    assert_eq!(polls, 4);
+}
+
+#[rstest]
+async fn test_async_map_with_stateful_closure() {
+    let mut calls = 0;
+    let numbered = iter(["a", "b", "c"])
+        .map_async(|item| {
+            calls += 1;
+            let index = calls;
+            async move { (index, item) }
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(numbered, vec![(1, "a"), (2, "b"), (3, "c")]);
+}
+
+#[rstest]
+async fn test_async_map_future_holding_rc_across_await() {
+    let labels = iter([1, 2])
+        .map_async(|item| async move {
+            let label = Rc::new(format!("item-{item}"));
+            yield_now().await;
+            label.to_string()
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(labels, vec!["item-1", "item-2"]);
+
+    let awaited = iter([3])
+        .map_async(|item| async move {
+            let shared = Rc::new(item);
+            yield_now().await;
+            *shared
+        })
+        .await
+        .collect::<Vec<_>>();
+
+    assert_eq!(awaited, vec![3]);
+}
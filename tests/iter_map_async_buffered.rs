@@ -0,0 +1,66 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use rstest::rstest;
+
+#[rstest]
+async fn test_buffered_keeps_input_order() {
+    let items = [4, 3, 2, 1];
+
+    let mapped_items = iter(items)
+        .map_async_buffered(4, |item| async move {
+            sleep(Duration::from_millis(item * 25)).await;
+            item * 2
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(mapped_items, vec![8, 6, 4, 2]);
+}
+
+#[rstest]
+#[timeout(Duration::from_millis(300))]
+async fn test_buffered_runs_futures_concurrently() {
+    let items = [1, 2, 3, 4];
+
+    let mapped_items = iter(items)
+        .map_async_buffered(4, |item| async move {
+            sleep(Duration::from_millis(100)).await;
+            item * 2
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(mapped_items, vec![2, 4, 6, 8]);
+}
+
+#[rstest]
+async fn test_buffered_respects_limit() {
+    let running = AtomicUsize::new(0);
+    let max_running = AtomicUsize::new(0);
+
+    let mapped_items = iter(1..=10)
+        .map_async_buffered(3, |item| {
+            let running = &running;
+            let max_running = &max_running;
+            async move {
+                let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now_running, Ordering::SeqCst);
+                sleep(Duration::from_millis(20)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                item
+            }
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(mapped_items, (1..=10).collect::<Vec<_>>());
+    assert_eq!(max_running.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,98 @@
+use std::{
+    collections::HashMap,
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::{block_on, sleep, spawn};
+use common::assert_size_hint;
+use rstest::rstest;
+
+mod common;
+
+#[rstest]
+async fn test_map_mut_borrows_captured_state() {
+    let mut seen = Vec::new();
+
+    let doubled = iter([1, 2, 3])
+        .map_async_mut(async |item| {
+            sleep(Duration::from_millis(10)).await;
+            seen.push(item);
+            item * 2
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(doubled, vec![2, 4, 6]);
+    assert_eq!(seen, vec![1, 2, 3]);
+}
+
+#[rstest]
+async fn test_map_mut_keeps_state_across_items() {
+    let mut counts = HashMap::new();
+
+    let running_counts = iter(["a", "b", "a", "a"])
+        .map_async_mut(async |word| {
+            let count = counts.entry(word).or_insert(0);
+            *count += 1;
+            (word, *count)
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(running_counts, vec![("a", 1), ("b", 1), ("a", 2), ("a", 3)]);
+    assert_eq!(counts.len(), 2);
+}
+
+#[rstest]
+async fn test_map_mut_is_lazy() {
+    let mut calls = 0;
+
+    let mut mapped = iter(1..=3).map_async_mut(async |item| {
+        calls += 1;
+        item
+    });
+    assert_eq!(mapped.next_async().await, Some(1));
+    drop(mapped);
+
+    assert_eq!(calls, 1);
+}
+
+#[rstest]
+async fn test_map_mut_runs_on_spawned_task() {
+    let mut seen = Vec::new();
+    let mapped = iter([1, 2, 3]).map_async_mut(async move |item| {
+        sleep(Duration::from_millis(1)).await;
+        seen.push(item);
+        (item * 2, seen.len())
+    });
+
+    let doubled = spawn(mapped.async_collect::<Vec<_>>()).await;
+
+    assert_eq!(doubled, vec![(2, 1), (4, 2), (6, 3)]);
+}
+
+#[rstest]
+#[should_panic(expected = "polled after panic")]
+fn test_map_mut_polled_after_panic() {
+    let mut mapped = iter([1, 2]).map_async_mut(async |item| {
+        assert_ne!(item, 1, "first item");
+        item
+    });
+
+    let first = panic::catch_unwind(AssertUnwindSafe(|| block_on(mapped.next_async())));
+    assert!(first.is_err());
+    block_on(mapped.next_async());
+}
+
+#[rstest]
+fn test_map_mut_size_hint() {
+    assert_size_hint(
+        |items| items.map_async_mut(async |item| item + 1),
+        (3, Some(3)),
+    );
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use rstest::rstest;
+
+#[rstest]
+async fn test_unordered_yields_in_completion_order() {
+    let items = [4, 3, 2, 1];
+
+    let mapped_items = iter(items)
+        .map_async_unordered(4, |item| async move {
+            sleep(Duration::from_millis(item * 25)).await;
+            item * 2
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(mapped_items, vec![2, 4, 6, 8]);
+}
+
+#[rstest]
+#[timeout(Duration::from_millis(300))]
+async fn test_unordered_is_not_blocked_by_slow_item() {
+    let items = [200, 10, 10, 10, 10];
+
+    let mut mapped = iter(items).map_async_unordered(2, |item| async move {
+        sleep(Duration::from_millis(item)).await;
+        item
+    });
+
+    assert_eq!(mapped.next_async().await, Some(10));
+    assert_eq!(mapped.async_size_hint(), (4, Some(4)));
+
+    let rest = mapped.async_collect::<Vec<_>>().await;
+    assert_eq!(rest, vec![10, 10, 10, 200]);
+}
+
+#[rstest]
+async fn test_unordered_with_no_items() {
+    let mapped_items = iter(std::iter::empty::<u64>())
+        .map_async_unordered(2, |item| async move { item })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert!(mapped_items.is_empty());
+}
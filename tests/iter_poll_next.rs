@@ -0,0 +1,186 @@
+use std::{
+    marker::PhantomPinned,
+    ops::RangeInclusive,
+    pin::{Pin, pin},
+    task::{Context, Poll, Waker},
+};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter, poll_fn},
+};
+use async_std::task::yield_now;
+use common::{Delayed, ticker};
+use pin_project_lite::pin_project;
+use rstest::rstest;
+
+mod common;
+
+/// A future that sums every item of an async iterator by polling it by hand.
+struct Sum<I> {
+    iter: I,
+    total: u32,
+}
+
+impl<I> Future for Sum<I>
+where
+    I: AsyncIterator<Item = u32> + Unpin,
+{
+    type Output = u32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.iter).poll_next(cx) {
+                Poll::Ready(Some(item)) => this.total += item,
+                Poll::Ready(None) => return Poll::Ready(this.total),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// A [`ticker`] that is not `Unpin`.
+    struct PinnedTicker {
+        #[pin]
+        ticker: Delayed<RangeInclusive<u32>>,
+        #[pin]
+        _pinned: PhantomPinned,
+    }
+}
+
+impl AsyncIterator for PinnedTicker {
+    type Item = u32;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().ticker.poll_next(cx)
+    }
+}
+
+#[rstest]
+async fn test_poll_next_drives_combinators_from_hand_written_future() {
+    let mapped = iter([1, 2, 3, 4])
+        .map_async(|item| async move {
+            yield_now().await;
+            item * 2
+        })
+        .filter_async(|item| async move {
+            yield_now().await;
+            item > 2
+        });
+
+    let total = Sum {
+        iter: mapped,
+        total: 0,
+    }
+    .await;
+
+    assert_eq!(total, 18);
+}
+
+#[rstest]
+fn test_poll_next_returns_pending_and_resumes() {
+    let mut mapped = iter([1, 2]).map_async(|item| async move {
+        yield_now().await;
+        item * 10
+    });
+
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(Pin::new(&mut mapped).poll_next(&mut cx), Poll::Pending);
+    assert_eq!(
+        Pin::new(&mut mapped).poll_next(&mut cx),
+        Poll::Ready(Some(10))
+    );
+    assert_eq!(Pin::new(&mut mapped).poll_next(&mut cx), Poll::Pending);
+    assert_eq!(
+        Pin::new(&mut mapped).poll_next(&mut cx),
+        Poll::Ready(Some(20))
+    );
+    assert_eq!(Pin::new(&mut mapped).poll_next(&mut cx), Poll::Ready(None));
+}
+
+#[rstest]
+async fn test_timer_source_through_map_async_and_collect() {
+    let doubled = ticker(3)
+        .map_async(|item| async move { item * 2 })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(doubled, vec![2, 4, 6]);
+}
+
+#[rstest]
+async fn test_timer_source_through_hand_written_future() {
+    let total = Sum {
+        iter: ticker(4),
+        total: 0,
+    }
+    .await;
+    assert_eq!(total, 10);
+
+    let doubled = ticker(3)
+        .map_async(|item| async move { item * 2 })
+        .await
+        .collect::<Vec<_>>();
+    assert_eq!(doubled, vec![2, 4, 6]);
+}
+
+#[rstest]
+async fn test_timer_source_through_combinators() {
+    let pairs = ticker(5)
+        .async_take(2)
+        .async_chain(ticker(1))
+        .async_zip(ticker(3).flat_map_async(|item| async move { ticker(item) }))
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(pairs, vec![(1, 1), (2, 1), (1, 2)]);
+}
+
+#[rstest]
+async fn test_next_async_keeps_progress_when_dropped() {
+    let mut ticker = ticker(2);
+
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(pin!(ticker.next_async()).poll(&mut cx), Poll::Pending);
+
+    assert_eq!(ticker.next_async().await, Some(1));
+    assert_eq!(ticker.next_async().await, Some(2));
+    assert_eq!(ticker.next_async().await, None);
+}
+
+#[rstest]
+async fn test_combinators_accept_sources_that_are_not_unpin() {
+    let source = PinnedTicker {
+        ticker: ticker(3),
+        _pinned: PhantomPinned,
+    };
+
+    let doubled = source
+        .map_async(|item| async move { item * 2 })
+        .filter_async(|item| async move { item > 2 })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(doubled, vec![4, 6]);
+}
+
+#[rstest]
+async fn test_poll_fn_source() {
+    let mut remaining = 3;
+    let source = poll_fn(move |_cx| {
+        if remaining == 0 {
+            return Poll::Ready(None);
+        }
+        remaining -= 1;
+        Poll::Ready(Some(remaining))
+    });
+
+    let items = source
+        .map_async(|item| async move { item + 1 })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(items, vec![3, 2, 1]);
+}
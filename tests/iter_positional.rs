@@ -0,0 +1,167 @@
+use std::cell::Cell;
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use common::counted;
+use rstest::rstest;
+
+mod common;
+
+#[rstest]
+#[case(0, vec![])]
+#[case(2, vec![0, 1])]
+#[case(10, vec![0, 1, 2, 3, 4])]
+async fn test_take(#[case] n: usize, #[case] expected: Vec<usize>) {
+    let taken = iter(0..5).async_take(n).async_collect::<Vec<_>>().await;
+
+    assert_eq!(taken, expected);
+}
+
+#[rstest]
+async fn test_take_does_not_poll_past_limit() {
+    let pulls = Cell::new(0);
+    let mut mapped = 0;
+
+    let taken = counted(0..100, &pulls)
+        .map_async(|item| {
+            mapped += 1;
+            async move { item * 2 }
+        })
+        .async_take(3)
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(taken, vec![0, 2, 4]);
+    assert_eq!(pulls.get(), 3);
+    assert_eq!(mapped, 3);
+}
+
+#[rstest]
+#[case(0..10, 0, (0, Some(0)))]
+#[case(0..10, 3, (3, Some(3)))]
+#[case(0..2, 3, (2, Some(2)))]
+fn test_take_size_hint(
+    #[case] items: std::ops::Range<usize>,
+    #[case] n: usize,
+    #[case] expected: (usize, Option<usize>),
+) {
+    assert_eq!(iter(items).async_take(n).async_size_hint(), expected);
+}
+
+#[rstest]
+fn test_take_size_hint_unbounded_source() {
+    let pulls = Cell::new(0);
+    let source = counted(0..100, &pulls);
+
+    assert_eq!(source.async_take(4).async_size_hint(), (0, Some(4)));
+}
+
+#[rstest]
+#[case(0, vec![0, 1, 2, 3, 4])]
+#[case(2, vec![2, 3, 4])]
+#[case(10, vec![])]
+async fn test_skip(#[case] n: usize, #[case] expected: Vec<usize>) {
+    let skipped = iter(0..5).async_skip(n).async_collect::<Vec<_>>().await;
+
+    assert_eq!(skipped, expected);
+}
+
+#[rstest]
+async fn test_skip_is_lazy_and_does_not_poll_past_end() {
+    let pulls = Cell::new(0);
+    let mut skipped = counted(0..3, &pulls).async_skip(5);
+    assert_eq!(pulls.get(), 0);
+
+    assert_eq!(skipped.next_async().await, None);
+    assert_eq!(pulls.get(), 4);
+}
+
+#[rstest]
+#[case(0..10, 3, (7, Some(7)))]
+#[case(0..2, 3, (0, Some(0)))]
+fn test_skip_size_hint(
+    #[case] items: std::ops::Range<usize>,
+    #[case] n: usize,
+    #[case] expected: (usize, Option<usize>),
+) {
+    assert_eq!(iter(items).async_skip(n).async_size_hint(), expected);
+}
+
+#[rstest]
+#[case(0..10, 1, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9])]
+#[case(0..10, 3, vec![0, 3, 6, 9])]
+#[case(0..10, 4, vec![0, 4, 8])]
+#[case(0..0, 2, vec![])]
+async fn test_step_by(
+    #[case] items: std::ops::Range<usize>,
+    #[case] step: usize,
+    #[case] expected: Vec<usize>,
+) {
+    let stepped = iter(items)
+        .async_step_by(step)
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(stepped, expected);
+}
+
+#[rstest]
+async fn test_step_by_size_hint_stays_accurate() {
+    let mut stepped = iter(0..10).async_step_by(4);
+
+    for expected in [3, 2, 1, 0] {
+        assert_eq!(stepped.async_size_hint(), (expected, Some(expected)));
+        stepped.next_async().await;
+    }
+}
+
+#[rstest]
+async fn test_step_by_does_not_poll_past_needed() {
+    let pulls = Cell::new(0);
+    let mut stepped = counted(0..100, &pulls).async_step_by(5);
+
+    assert_eq!(stepped.next_async().await, Some(0));
+    assert_eq!(stepped.next_async().await, Some(5));
+    assert_eq!(pulls.get(), 6);
+}
+
+#[rstest]
+#[should_panic(expected = "step must be greater than zero")]
+fn test_step_by_zero_panics() {
+    let _ = iter(0..10).async_step_by(0);
+}
+
+#[rstest]
+async fn test_nth() {
+    let pulls = Cell::new(0);
+    let mut source = counted(0..5, &pulls);
+
+    assert_eq!(source.async_nth(2).await, Some(2));
+    assert_eq!(pulls.get(), 3);
+    assert_eq!(source.async_nth(0).await, Some(3));
+    assert_eq!(source.async_nth(5).await, None);
+}
+
+#[rstest]
+#[case(vec![], None)]
+#[case(vec![1, 2, 3], Some(3))]
+async fn test_last(#[case] items: Vec<u32>, #[case] expected: Option<u32>) {
+    let last = iter(items)
+        .map_async(|item| async move { item })
+        .async_last()
+        .await;
+
+    assert_eq!(last, expected);
+}
+
+#[rstest]
+#[case(0, 0)]
+#[case(7, 7)]
+async fn test_count(#[case] len: usize, #[case] expected: usize) {
+    let pulls = Cell::new(0);
+
+    assert_eq!(counted(0..len, &pulls).async_count().await, expected);
+    assert_eq!(pulls.get(), len + 1);
+}
@@ -0,0 +1,72 @@
+use std::{cell::Cell, time::Duration};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use common::{assert_size_hint, counted};
+use rstest::rstest;
+
+mod common;
+
+#[rstest]
+async fn test_scan_running_total() {
+    let totals = iter([1, 2, 3, 4])
+        .scan_async(0, |total, item| async move {
+            sleep(Duration::from_millis(1)).await;
+            let total = total + item;
+            Some((total, total))
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(totals, vec![1, 3, 6, 10]);
+}
+
+#[rstest]
+async fn test_scan_session_windows() {
+    // Timestamps more than 10 apart start a new session.
+    let sessions = iter([1, 3, 8, 30, 32, 60])
+        .scan_async((0, None), |(session, last), timestamp: u32| async move {
+            sleep(Duration::from_millis(1)).await;
+            let session = match last {
+                Some(last) if timestamp - last > 10 => session + 1,
+                _ => session,
+            };
+            Some(((session, Some(timestamp)), (session, timestamp)))
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(
+        sessions,
+        vec![(0, 1), (0, 3), (0, 8), (1, 30), (1, 32), (2, 60)]
+    );
+}
+
+#[rstest]
+async fn test_scan_ends_early_and_is_fused() {
+    let pulls = Cell::new(0);
+    let mut scanned =
+        counted([5, 4, 3, 2, 1], &pulls).scan_async(10, |budget: u32, cost| async move {
+            let budget = budget.checked_sub(cost)?;
+            Some((budget, cost))
+        });
+
+    assert_eq!(scanned.next_async().await, Some(5));
+    assert_eq!(scanned.next_async().await, Some(4));
+    assert_eq!(scanned.next_async().await, None);
+    assert_eq!(scanned.next_async().await, None);
+    assert_eq!(scanned.async_size_hint(), (0, Some(0)));
+    drop(scanned);
+    assert_eq!(pulls.get(), 3);
+}
+
+#[rstest]
+fn test_scan_size_hint() {
+    assert_size_hint(
+        |items| items.scan_async((), |state, item| std::future::ready(Some((state, item)))),
+        (0, Some(3)),
+    );
+}
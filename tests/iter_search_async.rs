@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use rstest::rstest;
+
+/// Simulates checking whether a host responds.
+async fn responds(host: &str) -> bool {
+    sleep(Duration::from_millis(10)).await;
+    host.ends_with(".online")
+}
+
+#[rstest]
+#[case(vec![], false)]
+#[case(vec!["a.offline", "b.offline"], false)]
+#[case(vec!["a.offline", "b.online"], true)]
+async fn test_any_async(#[case] hosts: Vec<&str>, #[case] expected: bool) {
+    let any_up = iter(hosts)
+        .any_async(async |host| responds(host).await)
+        .await;
+
+    assert_eq!(any_up, expected);
+}
+
+#[rstest]
+async fn test_any_async_stops_at_first_match() {
+    let mut hosts = iter(["a.offline", "b.online", "c.online", "d.offline"]);
+    let mut checked = vec![];
+
+    let any_up = hosts
+        .any_async(async |host| {
+            checked.push(host);
+            responds(host).await
+        })
+        .await;
+
+    assert!(any_up);
+    assert_eq!(checked, vec!["a.offline", "b.online"]);
+    assert_eq!(hosts.next_async().await, Some("c.online"));
+}
+
+#[rstest]
+#[case(vec![], true)]
+#[case(vec![2, 4, 6], true)]
+#[case(vec![2, 3, 6], false)]
+async fn test_all_async(#[case] items: Vec<u32>, #[case] expected: bool) {
+    let all_even = iter(items.clone())
+        .all_async(async |item| item % 2 == 0)
+        .await;
+
+    assert_eq!(all_even, items.iter().all(|item| item % 2 == 0));
+    assert_eq!(all_even, expected);
+}
+
+#[rstest]
+async fn test_all_async_stops_at_first_mismatch() {
+    let mut items = iter([2, 3, 4, 5]);
+
+    assert!(!items.all_async(async |item| item % 2 == 0).await);
+    assert_eq!(items.next_async().await, Some(4));
+}
+
+#[rstest]
+async fn test_find_async_borrows_item() {
+    let mut items = iter([String::from("a"), String::from("bb"), String::from("cc")]);
+
+    let found = items
+        .find_async(async |item| {
+            sleep(Duration::from_millis(1)).await;
+            item.len() == 2
+        })
+        .await;
+
+    assert_eq!(found.as_deref(), Some("bb"));
+    assert_eq!(items.next_async().await.as_deref(), Some("cc"));
+}
+
+#[rstest]
+async fn test_find_async_none() {
+    let found = iter([1, 3, 5]).find_async(async |item| *item > 5).await;
+
+    assert_eq!(found, None);
+}
+
+#[rstest]
+#[case(vec!["x", "2", "3"], Some(2))]
+#[case(vec!["x", "y"], None)]
+async fn test_find_map_async(#[case] items: Vec<&str>, #[case] expected: Option<u32>) {
+    let mut pulled = 0;
+    let found = iter(items.clone())
+        .find_map_async(async |item| {
+            pulled += 1;
+            item.parse::<u32>().ok()
+        })
+        .await;
+
+    assert_eq!(found, expected);
+    assert_eq!(
+        pulled,
+        items
+            .iter()
+            .position(|item| item.parse::<u32>().is_ok())
+            .map_or(items.len(), |position| position + 1)
+    );
+}
+
+#[rstest]
+#[case(vec![1, 3, 4, 6], Some(2))]
+#[case(vec![4], Some(0))]
+#[case(vec![1, 3], None)]
+#[case(vec![], None)]
+async fn test_position_async(#[case] items: Vec<u32>, #[case] expected: Option<usize>) {
+    let position = iter(items).position_async(async |item| item % 2 == 0).await;
+
+    assert_eq!(position, expected);
+}
+
+#[rstest]
+async fn test_position_async_resumes_counting_from_zero() {
+    let mut items = iter([1, 2, 3, 4]);
+
+    assert_eq!(items.position_async(async |item| item == 2).await, Some(1));
+    assert_eq!(items.position_async(async |item| item == 4).await, Some(1));
+}
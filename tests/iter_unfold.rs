@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter, unfold},
+};
+use async_std::task::sleep;
+use rstest::rstest;
+
+#[rstest]
+async fn test_unfold_yields_until_none() {
+    let countdown = unfold(3, |remaining| async move {
+        sleep(Duration::from_millis(10)).await;
+        (remaining > 0).then_some((remaining - 1, remaining))
+    });
+
+    assert_eq!(countdown.async_collect::<Vec<_>>().await, vec![3, 2, 1]);
+}
+
+#[rstest]
+async fn test_unfold_is_not_called_after_none() {
+    let mut calls = 0;
+    let mut source = unfold((), |()| {
+        calls += 1;
+        async { None::<((), u32)> }
+    });
+
+    assert_eq!(source.next_async().await, None);
+    assert_eq!(source.next_async().await, None);
+    assert_eq!(source.async_size_hint(), (0, Some(0)));
+    drop(source);
+    assert_eq!(calls, 1);
+}
+
+#[rstest]
+async fn test_unfold_with_combinators() {
+    let pages = unfold(0, async |page| {
+        sleep(Duration::from_millis(10)).await;
+        (page < 3).then(|| (page + 1, vec![page; 2]))
+    })
+    .flat_map_async(|page| async move { iter(page) })
+    .map_async(|item| async move { item * 10 })
+    .async_collect::<Vec<_>>()
+    .await;
+
+    assert_eq!(pages, vec![0, 0, 10, 10, 20, 20]);
+}
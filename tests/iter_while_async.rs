@@ -0,0 +1,133 @@
+use std::{cell::Cell, time::Duration};
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use common::counted;
+use rstest::rstest;
+
+mod common;
+
+#[rstest]
+#[case(vec![1, 2, 5, 1], vec![1, 2])]
+#[case(vec![5, 1], vec![])]
+#[case(vec![1, 2], vec![1, 2])]
+#[case(vec![], vec![])]
+async fn test_take_while(#[case] items: Vec<u32>, #[case] expected: Vec<u32>) {
+    let taken = iter(items.clone())
+        .take_while_async(|item| {
+            let item = *item;
+            async move {
+                sleep(Duration::from_millis(1)).await;
+                item < 3
+            }
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(taken, expected);
+    assert_eq!(
+        taken,
+        items
+            .into_iter()
+            .take_while(|item| *item < 3)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[rstest]
+async fn test_take_while_is_fused_and_awaits_predicate_once_per_item() {
+    let pulls = Cell::new(0);
+    let calls = Cell::new(0);
+    let mut taken = counted([1, 2, 5, 1], &pulls).take_while_async(|item| {
+        calls.set(calls.get() + 1);
+        let item = *item;
+        async move { item < 3 }
+    });
+
+    assert_eq!(taken.next_async().await, Some(1));
+    assert_eq!(taken.next_async().await, Some(2));
+    assert_eq!(taken.next_async().await, None);
+    assert_eq!(taken.next_async().await, None);
+    assert_eq!(pulls.get(), 3);
+    assert_eq!(calls.get(), 3);
+    assert_eq!(taken.async_size_hint(), (0, Some(0)));
+}
+
+#[rstest]
+#[case(vec![1, 2, 5, 1, 6], vec![5, 1, 6])]
+#[case(vec![1, 2], vec![])]
+#[case(vec![5, 1], vec![5, 1])]
+async fn test_skip_while(#[case] items: Vec<u32>, #[case] expected: Vec<u32>) {
+    let skipped = iter(items.clone())
+        .skip_while_async(|item| {
+            let item = *item;
+            async move {
+                sleep(Duration::from_millis(1)).await;
+                item < 3
+            }
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(skipped, expected);
+    assert_eq!(
+        skipped,
+        items
+            .into_iter()
+            .skip_while(|item| *item < 3)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[rstest]
+async fn test_skip_while_stops_calling_predicate() {
+    let calls = Cell::new(0);
+    let mut skipped = iter([1, 2, 5, 1, 6]).skip_while_async(|item| {
+        calls.set(calls.get() + 1);
+        let item = *item;
+        async move { item < 3 }
+    });
+
+    assert_eq!(skipped.async_size_hint(), (0, Some(5)));
+    assert_eq!(skipped.next_async().await, Some(5));
+    assert_eq!(skipped.async_size_hint(), (2, Some(2)));
+    assert_eq!(skipped.async_collect::<Vec<_>>().await, vec![1, 6]);
+    assert_eq!(calls.get(), 3);
+}
+
+#[rstest]
+#[case(vec!["1", "2", "three", "4"], vec![1, 2])]
+#[case(vec!["1", "2"], vec![1, 2])]
+#[case(vec!["one"], vec![])]
+async fn test_map_while(#[case] items: Vec<&str>, #[case] expected: Vec<u32>) {
+    let mapped = iter(items)
+        .map_while_async(|item| async move {
+            sleep(Duration::from_millis(1)).await;
+            item.parse::<u32>().ok()
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(mapped, expected);
+}
+
+#[rstest]
+async fn test_map_while_is_fused() {
+    let pulls = Cell::new(0);
+    let calls = Cell::new(0);
+    let mut mapped = counted([1, 2, 0, 4], &pulls).map_while_async(|item| {
+        calls.set(calls.get() + 1);
+        async move { (item > 0).then_some(item * 10) }
+    });
+
+    assert_eq!(mapped.next_async().await, Some(10));
+    assert_eq!(mapped.next_async().await, Some(20));
+    assert_eq!(mapped.next_async().await, None);
+    assert_eq!(mapped.next_async().await, None);
+    assert_eq!(pulls.get(), 3);
+    assert_eq!(calls.get(), 3);
+    assert_eq!(mapped.async_size_hint(), (0, Some(0)));
+}
@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+};
+use async_std::task::sleep;
+use rstest::rstest;
+
+#[rstest]
+#[case(vec![], vec![], vec![])]
+#[case(vec![1, 2, 3], vec!["a", "b"], vec![(1, "a"), (2, "b")])]
+#[case(vec![1], vec!["a", "b"], vec![(1, "a")])]
+async fn test_zip(
+    #[case] left: Vec<u32>,
+    #[case] right: Vec<&'static str>,
+    #[case] expected: Vec<(u32, &'static str)>,
+) {
+    let zipped = iter(left)
+        .async_zip(iter(right))
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(zipped, expected);
+}
+
+#[rstest]
+#[timeout(Duration::from_millis(500))]
+async fn test_zip_polls_both_sides_concurrently() {
+    let slow = |item: u32| async move {
+        sleep(Duration::from_millis(100)).await;
+        item
+    };
+
+    let zipped = iter([1, 2, 3])
+        .map_async(slow)
+        .async_zip(iter([4, 5, 6]).map_async(slow))
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(zipped, vec![(1, 4), (2, 5), (3, 6)]);
+}
+
+#[rstest]
+async fn test_zip_keeps_item_ready_before_its_partner() {
+    let zipped = iter([1, 2])
+        .async_zip(iter([10, 20]).map_async(|item| async move {
+            sleep(Duration::from_millis(item)).await;
+            item
+        }))
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(zipped, vec![(1, 10), (2, 20)]);
+}
+
+#[rstest]
+#[case(0..5, 0..3, (3, Some(3)))]
+#[case(0..5, 0..10, (5, Some(5)))]
+fn test_zip_size_hint(
+    #[case] left: std::ops::Range<usize>,
+    #[case] right: std::ops::Range<usize>,
+    #[case] expected: (usize, Option<usize>),
+) {
+    assert_eq!(
+        iter(left).async_zip(iter(right)).async_size_hint(),
+        expected
+    );
+}
+
+#[rstest]
+fn test_zip_size_hint_unbounded() {
+    let zipped = iter(0..).async_zip(iter(0..4));
+
+    assert_eq!(zipped.async_size_hint(), (4, Some(4)));
+}
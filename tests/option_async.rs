@@ -30,3 +30,29 @@ async fn test_option_map_async(#[values(Some(1), None, Some(2))] value: Option<u
 
     assert_eq!(y, ya);
 }
+
+#[rstest]
+async fn test_option_map_async_infers_argument_type() {
+    let length = Some(String::from("abc"))
+        .map_async(|s| async move { s.len() })
+        .await;
+
+    assert_eq!(length, Some(3));
+}
+
+#[rstest]
+async fn test_option_map_async_closure_borrows_state_mutably(
+    #[values(Some(1), None, Some(2))] value: Option<u32>,
+) {
+    let mut seen = Vec::new();
+    let ya = value
+        .map_async(async |val| {
+            sleep(Duration::from_millis(10)).await;
+            seen.push(val);
+            val + 1
+        })
+        .await;
+
+    assert_eq!(ya, value.map(|val| val + 1));
+    assert_eq!(seen, value.into_iter().collect::<Vec<_>>());
+}
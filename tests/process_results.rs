@@ -1,15 +1,29 @@
-use std::time::Duration;
+use std::{
+    collections::{BTreeSet, HashMap},
+    error::Error,
+    fmt::{Display, Formatter},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
 
-use async_iter_ext::{AsyncIterTools, iter::process_result::ProcessResultsStrategy};
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{
+        iter,
+        process_result::{ProcessResultsError, ProcessResultsStopReason, ProcessResultsStrategy},
+    },
+};
 use async_std::task::sleep;
+use common::Delayed;
 use rstest::rstest;
 
+mod common;
+
 #[rstest]
 async fn test_process_results_default_strategy_only_success() {
     let items = [1, 2, 3, 4];
 
-    let res = items
-        .into_iter()
+    let res = iter(items)
         .map_async(|item| async move {
             sleep(Duration::from_millis(100)).await;
             Ok(item * 2)
@@ -25,8 +39,7 @@ async fn test_process_results_default_strategy_only_success() {
 async fn test_process_results_default_strategy_success_and_errors() {
     let items = [1, 2, 3, 4];
 
-    let res = items
-        .into_iter()
+    let res = iter(items)
         .map_async(|item| async move {
             sleep(Duration::from_millis(100)).await;
             if item > 2 {
@@ -49,8 +62,7 @@ async fn test_process_results_default_strategy_success_and_errors() {
 async fn test_process_results_break_on_error_strategy_only_success() {
     let items = [1, 2, 3, 4];
 
-    let res = items
-        .into_iter()
+    let res = iter(items)
         .map_async(|item| async move {
             sleep(Duration::from_millis(100)).await;
             Ok(item * 2)
@@ -67,8 +79,7 @@ async fn test_process_results_break_on_error_strategy_only_success() {
 async fn test_process_results_break_on_error_strategy_success_and_errors() {
     let items = [1, 2, 3, 4];
 
-    let res = items
-        .into_iter()
+    let res = iter(items)
         .map_async(|item| async move {
             sleep(Duration::from_millis(100)).await;
             if item > 2 {
@@ -89,8 +100,7 @@ async fn test_process_results_break_on_error_strategy_success_and_errors() {
 async fn test_process_results_break_on_error_strategy_only_success_into_result() {
     let items = [1, 2, 3, 4];
 
-    let res = items
-        .into_iter()
+    let res = iter(items)
         .map_async(|item| async move {
             sleep(Duration::from_millis(100)).await;
             Ok(item * 2)
@@ -107,8 +117,7 @@ async fn test_process_results_break_on_error_strategy_only_success_into_result()
 async fn test_process_results_break_on_error_strategy_success_and_errors_into_result() {
     let items = [1, 2, 3, 4];
 
-    let res = items
-        .into_iter()
+    let res = iter(items)
         .map_async(|item| async move {
             sleep(Duration::from_millis(100)).await;
             if item > 2 {
@@ -124,3 +133,348 @@ async fn test_process_results_break_on_error_strategy_success_and_errors_into_re
 
     assert!(res.is_err_and(|err| err == "Item was greater than 2"));
 }
+
+#[rstest]
+async fn test_process_results_break_on_error_stops_pulling_after_error() {
+    let calls = AtomicUsize::new(0);
+
+    let res = iter(1..=10)
+        .map_async(|item| {
+            let calls = &calls;
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                sleep(Duration::from_millis(10)).await;
+                if item == 3 {
+                    Err("Item was 3")
+                } else {
+                    Ok(item)
+                }
+            }
+        })
+        .process_results::<_, &str>()
+        .with_process_strategy(ProcessResultsStrategy::BreakOnError)
+        .await;
+
+    assert_eq!(res.into_errors(), vec!["Item was 3"]);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[rstest]
+async fn test_process_results_break_on_error_keep_successes_strategy() {
+    let items = [1, 2, 3, 4];
+
+    let res = iter(items)
+        .map_async(|item| async move {
+            sleep(Duration::from_millis(100)).await;
+            if item > 2 {
+                Err("Item was greater than 2")
+            } else {
+                Ok(item * 2)
+            }
+        })
+        .process_results::<_, &str>()
+        .with_process_strategy(ProcessResultsStrategy::BreakOnErrorKeepSuccesses)
+        .await;
+
+    assert_eq!(res.successes(), &vec![2, 4]);
+    assert_eq!(res.into_errors(), vec!["Item was greater than 2"]);
+}
+
+/// Returns the results for `1..=10`, with an error for every odd number.
+fn alternating() -> Vec<Result<u32, &'static str>> {
+    (1..=10)
+        .map(|item| {
+            if item % 2 == 1 {
+                Err("Item was odd")
+            } else {
+                Ok(item)
+            }
+        })
+        .collect()
+}
+
+/// Returns six errors followed by five successes.
+fn errors_first() -> Vec<Result<u32, &'static str>> {
+    (1..=11)
+        .map(|item| if item <= 6 { Err("Early") } else { Ok(item) })
+        .collect()
+}
+
+#[rstest]
+#[case(
+    ProcessResultsStrategy::Partition,
+    alternating(),
+    5,
+    5,
+    ProcessResultsStopReason::Exhausted
+)]
+#[case(
+    ProcessResultsStrategy::BreakOnError,
+    alternating(),
+    0,
+    1,
+    ProcessResultsStopReason::FirstError
+)]
+#[case(
+    ProcessResultsStrategy::MaxErrors(3),
+    alternating(),
+    2,
+    3,
+    ProcessResultsStopReason::MaxErrors
+)]
+#[case(
+    ProcessResultsStrategy::MaxErrors(6),
+    alternating(),
+    5,
+    5,
+    ProcessResultsStopReason::Exhausted
+)]
+#[case(
+    ProcessResultsStrategy::MaxErrorRatio { max_ratio: 0.4, min_samples: 4 },
+    alternating(),
+    2,
+    2,
+    ProcessResultsStopReason::MaxErrorRatio
+)]
+#[case(
+    ProcessResultsStrategy::MaxErrorRatio { max_ratio: 0.6, min_samples: 4 },
+    alternating(),
+    5,
+    5,
+    ProcessResultsStopReason::Exhausted
+)]
+#[case(
+    ProcessResultsStrategy::MaxErrorRatio { max_ratio: 0.5, min_samples: 10 },
+    errors_first(),
+    4,
+    6,
+    ProcessResultsStopReason::MaxErrorRatio
+)]
+#[case(
+    ProcessResultsStrategy::MaxErrorRatio { max_ratio: 0.6, min_samples: 10 },
+    errors_first(),
+    5,
+    6,
+    ProcessResultsStopReason::Exhausted
+)]
+async fn test_process_results_stop_reason(
+    #[case] strategy: ProcessResultsStrategy,
+    #[case] items: Vec<Result<u32, &'static str>>,
+    #[case] expected_successes: usize,
+    #[case] expected_errors: usize,
+    #[case] expected_stop_reason: ProcessResultsStopReason,
+) {
+    let res = iter(items)
+        .map_async(|item| async move { item })
+        .process_results::<_, &str>()
+        .with_process_strategy(strategy)
+        .await;
+
+    assert_eq!(res.successes().len(), expected_successes);
+    assert_eq!(res.errors().len(), expected_errors);
+    assert_eq!(res.stop_reason(), expected_stop_reason);
+}
+
+#[rstest]
+#[should_panic(expected = "max errors must be greater than zero")]
+fn test_process_results_zero_max_errors_panics() {
+    drop(
+        iter([Ok::<u32, &str>(1)])
+            .process_results::<_, &str>()
+            .with_process_strategy(ProcessResultsStrategy::MaxErrors(0)),
+    );
+}
+
+#[rstest]
+#[case(f64::NAN)]
+#[case(-0.1)]
+#[case(1.5)]
+#[should_panic(expected = "max error ratio must be between 0.0 and 1.0")]
+fn test_process_results_invalid_max_error_ratio_panics(#[case] max_ratio: f64) {
+    let strategy = ProcessResultsStrategy::MaxErrorRatio {
+        max_ratio,
+        min_samples: 1,
+    };
+
+    drop(
+        iter([Ok::<u32, &str>(1)])
+            .process_results::<_, &str>()
+            .with_process_strategy(strategy),
+    );
+}
+
+#[rstest]
+async fn test_process_results_with_indices() {
+    let items = [1, 2, 3, 4, 5];
+
+    let res = iter(items)
+        .map_async(|item| async move {
+            if item % 2 == 0 {
+                Err(format!("Item {item} was even"))
+            } else {
+                Ok(item)
+            }
+        })
+        .process_results::<_, String>()
+        .with_indices()
+        .await;
+
+    assert_eq!(res.success_indices(), &[0, 2, 4]);
+    assert_eq!(res.error_indices(), &[1, 3]);
+    assert_eq!(
+        res.indexed_errors().collect::<Vec<_>>(),
+        vec![
+            (1, &"Item 2 was even".to_string()),
+            (3, &"Item 4 was even".to_string())
+        ]
+    );
+    assert_eq!(
+        res.indexed_successes().collect::<Vec<_>>(),
+        vec![(0, &1), (2, &3), (4, &5)]
+    );
+}
+
+#[rstest]
+async fn test_process_results_without_indices() {
+    let res = iter([Ok(1), Err("Failed")])
+        .process_results::<i32, &str>()
+        .await;
+
+    assert!(res.success_indices().is_empty());
+    assert!(res.error_indices().is_empty());
+}
+
+#[derive(Debug, PartialEq)]
+struct ImportError(u32);
+
+impl Display for ImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not import {}", self.0)
+    }
+}
+
+impl Error for ImportError {}
+
+async fn import_all(items: Vec<u32>) -> Result<Vec<u32>, Box<dyn Error>> {
+    let imported = iter(items)
+        .map_async(|item| async move {
+            if item % 2 == 0 {
+                Err(ImportError(item))
+            } else {
+                Ok(item)
+            }
+        })
+        .process_results::<_, ImportError>()
+        .await
+        .into_aggregate_result()?;
+
+    Ok(imported)
+}
+
+#[rstest]
+async fn test_process_results_into_aggregate_result() {
+    assert_eq!(import_all(vec![1, 3]).await.unwrap(), vec![1, 3]);
+
+    let error = import_all(vec![1, 2, 3, 4]).await.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "2 errors occurred while processing results: could not import 2; could not import 4"
+    );
+    assert!(error.source().is_none());
+
+    let aggregate = error
+        .downcast::<ProcessResultsError<ImportError>>()
+        .unwrap();
+    assert_eq!(
+        aggregate.into_errors(),
+        vec![ImportError(2), ImportError(4)]
+    );
+}
+
+#[rstest]
+async fn test_process_results_into_aggregate_result_with_from() {
+    let res: Result<Vec<i32>, ProcessResultsError<&str>> = iter([Ok(1), Err("Failed")])
+        .process_results::<i32, &str>()
+        .await
+        .into();
+
+    assert_eq!(
+        res.unwrap_err().to_string(),
+        "1 error occurred while processing results: Failed"
+    );
+}
+
+#[rstest]
+async fn test_process_results_aggregate_of_messages_is_error() {
+    let error: Box<dyn Error> = iter([Ok(1), Err("first"), Err("second")])
+        .process_results::<i32, &str>()
+        .await
+        .into_aggregate_result()
+        .unwrap_err()
+        .into();
+
+    assert_eq!(
+        error.to_string(),
+        "2 errors occurred while processing results: first; second"
+    );
+}
+
+#[rstest]
+async fn test_process_results_with_collections() {
+    let items = [1, 2, 3, 4, 5, 6];
+
+    let res = iter(items)
+        .map_async(|item| async move {
+            if item > 3 {
+                Err("Item was greater than 3")
+            } else {
+                Ok((item, item * 10))
+            }
+        })
+        .process_results::<_, &str>()
+        .with_collections::<HashMap<_, _>, BTreeSet<_>>()
+        .await;
+
+    assert_eq!(res.successes().get(&2), Some(&20));
+    assert_eq!(res.len(), 3);
+    assert_eq!(res.errors().len(), 1);
+    assert_eq!(res.into_result().unwrap_err(), "Item was greater than 3");
+}
+
+#[rstest]
+async fn test_process_results_with_collections_and_strategy() {
+    let res = iter([Ok(1), Ok(1), Err("first"), Ok(2), Err("second")])
+        .process_results::<i32, &str>()
+        .with_process_strategy(ProcessResultsStrategy::MaxErrors(2))
+        .with_collections::<BTreeSet<_>, Vec<_>>()
+        .await;
+
+    assert_eq!(res.successes(), &BTreeSet::from([1, 2]));
+    assert_eq!(res.stop_reason(), ProcessResultsStopReason::MaxErrors);
+
+    let aggregate = res.into_aggregate_result().unwrap_err();
+    assert_eq!(aggregate.errors(), &vec!["first", "second"]);
+}
+
+#[rstest]
+async fn test_process_results_hand_written_source() {
+    let res = Delayed::new(vec![Ok(1), Err("first"), Ok(2), Err("second")])
+        .process_results::<i32, &str>()
+        .await;
+
+    assert_eq!(res.successes(), &vec![1, 2]);
+    assert_eq!(res.errors(), &vec!["first", "second"]);
+    assert_eq!(res.stop_reason(), ProcessResultsStopReason::Exhausted);
+}
+
+#[rstest]
+async fn test_process_results_hand_written_source_stops_on_strategy() {
+    let res = Delayed::new(vec![Ok(1), Err("first"), Ok(2)])
+        .process_results::<i32, &str>()
+        .with_process_strategy(ProcessResultsStrategy::BreakOnErrorKeepSuccesses)
+        .await;
+
+    assert_eq!(res.successes(), &vec![1]);
+    assert_eq!(res.errors(), &vec!["first"]);
+    assert_eq!(res.stop_reason(), ProcessResultsStopReason::FirstError);
+}
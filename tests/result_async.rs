@@ -115,3 +115,21 @@ async fn test_result_map_async(#[values(Some(1), None, Some(2))] value: Option<u
 
     assert_eq!(y, ya);
 }
+
+#[rstest]
+async fn test_result_map_err_async_closure_borrows_state_mutably(
+    #[values(Some(1), None, Some(2))] value: Option<u32>,
+) {
+    let mut failures = 0;
+    let ya = value
+        .ok_or(Error::Invalid)
+        .map_err_async(async |err| {
+            sleep(Duration::from_millis(10)).await;
+            failures += 1;
+            err
+        })
+        .await;
+
+    assert_eq!(ya, value.ok_or(Error::Invalid));
+    assert_eq!(failures, usize::from(value.is_none()));
+}
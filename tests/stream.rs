@@ -0,0 +1,68 @@
+#![cfg(feature = "futures")]
+
+use std::time::Duration;
+
+use async_iter_ext::{
+    AsyncIterTools,
+    iter::{AsyncIterator, iter},
+    stream::{Stream, from_stream},
+};
+use async_std::{
+    stream::{self, StreamExt},
+    task::sleep,
+};
+use common::ticker;
+use rstest::rstest;
+
+mod common;
+
+#[rstest]
+async fn test_from_stream_into_combinators() {
+    let items = from_stream(stream::from_iter([1, 2, 3, 4]))
+        .filter_async(|item| async move { item % 2 == 0 })
+        .map_async(|item| async move {
+            sleep(Duration::from_millis(10)).await;
+            item * 10
+        })
+        .async_collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(items, vec![20, 40]);
+}
+
+#[rstest]
+async fn test_into_stream_from_combinators() {
+    let stream = iter([1, 2, 3, 4])
+        .map_async(|item| async move {
+            sleep(Duration::from_millis(10)).await;
+            item * 2
+        })
+        .into_stream();
+    assert_eq!(stream.size_hint(), (4, Some(4)));
+
+    let mut filtered = stream.filter(|item| *item > 4);
+    assert_eq!(filtered.next().await, Some(6));
+    assert_eq!(filtered.next().await, Some(8));
+    assert_eq!(filtered.next().await, None);
+}
+
+#[rstest]
+async fn test_into_stream_from_timer_source() {
+    let mut stream = ticker(3)
+        .map_async(|item| async move { item * 2 })
+        .into_stream();
+
+    assert_eq!(stream.next().await, Some(2));
+    assert_eq!(stream.next().await, Some(4));
+    assert_eq!(stream.next().await, Some(6));
+    assert_eq!(stream.next().await, None);
+}
+
+#[rstest]
+async fn test_stream_round_trip() {
+    let mut stream = from_stream(stream::from_iter(["a", "b"])).into_stream();
+
+    assert_eq!(stream.next().await, Some("a"));
+    assert_eq!(stream.next().await, Some("b"));
+    assert_eq!(stream.next().await, None);
+}